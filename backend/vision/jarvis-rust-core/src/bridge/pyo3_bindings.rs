@@ -16,9 +16,13 @@ use crate::runtime::{RuntimeConfig, RuntimeManager};
 use pyo3::prelude::*;
 use pyo3::types::{PyBytes, PyDict};
 use pyo3::exceptions::{PyRuntimeError, PyValueError};
-use parking_lot::Mutex;
+use pyo3::AsPyPointer;
+use std::ptr;
+use parking_lot::{Condvar, Mutex};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::collections::HashMap;
+use std::time::Duration;
 use numpy::{PyArray1, PyArray2, PyArray3, PyReadonlyArray2, PyReadonlyArrayDyn};
 
 // ============================================================================
@@ -30,6 +34,9 @@ use numpy::{PyArray1, PyArray2, PyArray3, PyReadonlyArray2, PyReadonlyArrayDyn};
 pub struct PyScreenCapture {
     inner: Arc<ScreenCapture>,
     runtime: Arc<tokio::runtime::Runtime>,
+    /// Workers that run `subscribe()` callbacks off both the capture
+    /// loop and the channel relay thread.
+    runtime_manager: Arc<RuntimeManager>,
 }
 
 // These are safe because ScreenCapture no longer contains raw pointers
@@ -68,13 +75,17 @@ impl PyScreenCapture {
         
         let runtime = tokio::runtime::Runtime::new()
             .map_err(|e| PyRuntimeError::new_err(format!("Failed to create runtime: {}", e)))?;
-        
+
+        let runtime_manager = RuntimeManager::new(RuntimeConfig::default())
+            .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+
         Ok(Self {
             inner: Arc::new(capture),
             runtime: Arc::new(runtime),
+            runtime_manager: Arc::new(runtime_manager),
         })
     }
-    
+
     /// Capture screen to numpy array
     fn capture_to_numpy(&self, py: Python) -> PyResult<Py<PyArray3<u8>>> {
         let capture = self.inner.clone();
@@ -178,6 +189,445 @@ impl PyScreenCapture {
             }
         }).map_err(|e| PyRuntimeError::new_err(e.to_string()))
     }
+
+    /// Start a continuous, pool-backed capture stream. Each `next()` pulls
+    /// whatever frame is most recently ready without copying the whole
+    /// image per call, and yields `None` rather than blocking when no new
+    /// frame has arrived since the last pull. `max_inflight` bounds how
+    /// many pulled frames may be outstanding (not yet released) at once;
+    /// once that many are held, further pulls return `None` until the
+    /// caller releases one.
+    #[pyo3(signature = (max_inflight=4))]
+    fn stream(&self, max_inflight: usize) -> PyResult<PyFrameStream> {
+        let capture = self.inner.clone();
+        let pool = Arc::new(AdvancedBufferPool::new());
+
+        let ring = Arc::new(FrameRing {
+            latest: Mutex::new(None),
+            shape: Mutex::new((0, 0, 0)),
+            generation: AtomicU64::new(0),
+            outstanding: AtomicUsize::new(0),
+            max_inflight,
+            running: AtomicBool::new(true),
+        });
+
+        let worker_ring = ring.clone();
+        self.runtime.spawn(async move {
+            while worker_ring.running.load(Ordering::Acquire) {
+                let image = match capture.capture_async().await {
+                    Ok(image) => image,
+                    Err(_) => break,
+                };
+
+                let data = image.as_slice();
+                if let Ok(mut tracked) = pool.allocate(data.len()) {
+                    tracked.as_mut_slice().copy_from_slice(data);
+                    *worker_ring.shape.lock() = (image.width, image.height, image.channels);
+                    // Overwriting `latest` drops (and recycles) whichever
+                    // frame the consumer hadn't pulled yet — coalescing to
+                    // the newest frame instead of buffering a backlog.
+                    *worker_ring.latest.lock() = Some(tracked);
+                    worker_ring.generation.fetch_add(1, Ordering::Release);
+                }
+            }
+        });
+
+        Ok(PyFrameStream { ring, last_seen: 0 })
+    }
+
+    /// Subscribe a Python callback to be invoked on every new frame. The
+    /// capture loop never calls into Python directly: frames cross a
+    /// dedicated relay thread and are dispatched to a `RuntimeManager`
+    /// worker, which re-acquires the GIL and calls `callback` with a
+    /// dict of `{data, width, height, channels}`.
+    ///
+    /// When `coalesce` is `false`, frames are pushed onto a
+    /// fixed-capacity channel; once it's full, the capture loop's send
+    /// blocks, applying backpressure straight back to capture. When
+    /// `coalesce` is `true` (the default), there is no queue — only a
+    /// single overwritable slot — so a slow consumer never stalls
+    /// capture and always sees the newest frame, never a backlog.
+    ///
+    /// Returns a `Subscription` handle; call `unsubscribe()` (or drop the
+    /// handle) to stop both the capture loop and the relay thread.
+    #[pyo3(signature = (callback, coalesce=true))]
+    fn subscribe(&self, callback: PyObject, coalesce: bool) -> PyResult<PySubscription> {
+        let capture = self.inner.clone();
+        let runtime_manager = self.runtime_manager.clone();
+        let active = Arc::new(AtomicBool::new(true));
+        let producer_active = active.clone();
+
+        if coalesce {
+            let slot: Arc<(Mutex<Option<CapturedFrame>>, Condvar)> =
+                Arc::new((Mutex::new(None), Condvar::new()));
+
+            let producer_slot = slot.clone();
+            self.runtime.spawn(async move {
+                while producer_active.load(Ordering::Acquire) {
+                    let image = match capture.capture_async().await {
+                        Ok(image) => image,
+                        Err(_) => break,
+                    };
+                    let (lock, cvar) = &*producer_slot;
+                    *lock.lock() = Some(CapturedFrame::from_image(&image));
+                    cvar.notify_one();
+                }
+            });
+
+            let relay_active = active.clone();
+            std::thread::spawn(move || {
+                let (lock, cvar) = &*slot;
+                while relay_active.load(Ordering::Acquire) {
+                    let mut guard = lock.lock();
+                    if guard.is_none() {
+                        cvar.wait_for(&mut guard, SUBSCRIPTION_POLL_INTERVAL);
+                        continue;
+                    }
+                    let frame = guard.take().expect("checked is_none above");
+                    drop(guard);
+                    dispatch_subscription_frame(&runtime_manager, &callback, frame);
+                }
+            });
+        } else {
+            let (tx, rx) =
+                std::sync::mpsc::sync_channel::<CapturedFrame>(SUBSCRIPTION_QUEUE_DEPTH);
+
+            self.runtime.spawn(async move {
+                while producer_active.load(Ordering::Acquire) {
+                    let image = match capture.capture_async().await {
+                        Ok(image) => image,
+                        Err(_) => break,
+                    };
+                    if tx.send(CapturedFrame::from_image(&image)).is_err() {
+                        break;
+                    }
+                }
+            });
+
+            let relay_active = active.clone();
+            std::thread::spawn(move || {
+                while relay_active.load(Ordering::Acquire) {
+                    match rx.recv_timeout(SUBSCRIPTION_POLL_INTERVAL) {
+                        Ok(frame) => dispatch_subscription_frame(&runtime_manager, &callback, frame),
+                        Err(std::sync::mpsc::RecvTimeoutError::Timeout) => continue,
+                        Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => break,
+                    }
+                }
+            });
+        }
+
+        Ok(PySubscription { active })
+    }
+}
+
+/// Image payload handed from the capture loop to a subscription's relay
+/// thread.
+struct CapturedFrame {
+    data: Vec<u8>,
+    width: u32,
+    height: u32,
+    channels: u8,
+}
+
+impl CapturedFrame {
+    fn from_image(image: &ImageData) -> Self {
+        Self {
+            data: image.as_slice().to_vec(),
+            width: image.width,
+            height: image.height,
+            channels: image.channels,
+        }
+    }
+}
+
+/// How often an idle relay thread re-checks its `active` flag, so
+/// `unsubscribe()`/drop is noticed promptly without needing to interrupt
+/// a blocking channel read.
+const SUBSCRIPTION_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Bound on in-flight frames for a non-coalescing subscription before the
+/// capture loop's send blocks, applying backpressure.
+const SUBSCRIPTION_QUEUE_DEPTH: usize = 8;
+
+/// Hand a captured frame to a `RuntimeManager` worker, which re-acquires
+/// the GIL and invokes the subscriber's callback. Keeps the relay thread
+/// (and, upstream, the capture loop) off the Python call entirely.
+fn dispatch_subscription_frame(
+    runtime: &Arc<RuntimeManager>,
+    callback: &PyObject,
+    frame: CapturedFrame,
+) {
+    let callback = callback.clone();
+    let _ = runtime.spawn_cpu("subscription-callback", move || {
+        Python::with_gil(|py| {
+            let dict = PyDict::new(py);
+            let _ = dict.set_item("data", PyBytes::new(py, &frame.data));
+            let _ = dict.set_item("width", frame.width);
+            let _ = dict.set_item("height", frame.height);
+            let _ = dict.set_item("channels", frame.channels);
+            let _ = callback.call1(py, (dict,));
+        })
+    });
+}
+
+/// Handle returned by [`PyScreenCapture::subscribe`]. Dropping it (or
+/// calling `unsubscribe()` explicitly) stops the producer and relay
+/// threads; any frame already handed to a `RuntimeManager` worker is
+/// still delivered.
+#[pyclass(name = "Subscription", module = "jarvis_rust_core")]
+pub struct PySubscription {
+    active: Arc<AtomicBool>,
+}
+
+unsafe impl Send for PySubscription {}
+unsafe impl Sync for PySubscription {}
+
+#[pymethods]
+impl PySubscription {
+    /// Stop delivery. Safe to call more than once.
+    fn unsubscribe(&mut self) {
+        self.active.store(false, Ordering::Release);
+    }
+}
+
+impl Drop for PySubscription {
+    fn drop(&mut self) {
+        self.unsubscribe();
+    }
+}
+
+// ============================================================================
+// ZERO-COPY STREAMING CAPTURE
+// ============================================================================
+
+/// Producer ring backing [`PyScreenCapture::stream`]: a capture worker
+/// fills a pool-backed slot and bumps `generation`; `next()` only hands
+/// out a frame when `generation` has moved since the caller's last pull.
+struct FrameRing {
+    latest: Mutex<Option<TrackedBuffer>>,
+    shape: Mutex<(u32, u32, u8)>,
+    generation: AtomicU64,
+    outstanding: AtomicUsize,
+    max_inflight: usize,
+    running: AtomicBool,
+}
+
+/// A single frame pulled from [`PyFrameStream`]. Wraps an
+/// `Option<TrackedBuffer>` so a second `release()` call is a no-op
+/// rather than a use-after-free. A poll that finds no new frame yields
+/// `None` from the stream instead of a view.
+#[pyclass(name = "FrameView", module = "jarvis_rust_core")]
+pub struct PyFrameView {
+    buffer: Option<TrackedBuffer>,
+    ring: Arc<FrameRing>,
+    width: u32,
+    height: u32,
+    channels: u8,
+    /// Count of live buffer-protocol exports (e.g. `np.frombuffer` views),
+    /// mirroring `PyRustTrackedBuffer`: `release` refuses to return the
+    /// buffer to the pool while this is nonzero, rather than freeing it
+    /// out from under an outstanding view.
+    export_count: AtomicUsize,
+}
+
+unsafe impl Send for PyFrameView {}
+unsafe impl Sync for PyFrameView {}
+
+#[pymethods]
+impl PyFrameView {
+    /// True once this view's buffer has been returned to the pool by
+    /// `release()`.
+    fn is_empty(&self) -> bool {
+        self.buffer.is_none()
+    }
+
+    fn width(&self) -> u32 {
+        self.width
+    }
+
+    fn height(&self) -> u32 {
+        self.height
+    }
+
+    fn channels(&self) -> u8 {
+        self.channels
+    }
+
+    /// Copying accessor, kept for callers that don't need a zero-copy
+    /// view, or `None` if this view is empty. Prefer `np.frombuffer(view)`
+    /// (backed by `__getbuffer__`) on the hot path — unlike `as_numpy`,
+    /// which copies the whole frame on every pull, it reads the pool
+    /// buffer in place.
+    fn as_numpy(&self, py: Python) -> PyResult<Option<Py<PyArray1<u8>>>> {
+        Ok(self
+            .buffer
+            .as_ref()
+            .map(|tracked| PyArray1::from_slice(py, tracked.as_slice()).to_owned()))
+    }
+
+    /// Return the buffer to the pool. Safe to call more than once. Refuses
+    /// (raises) while a live buffer-protocol export (e.g. an outstanding
+    /// `memoryview`/numpy view) still exists, rather than freeing the pool
+    /// buffer out from under it.
+    fn release(&mut self) -> PyResult<()> {
+        if self.export_count.load(Ordering::Acquire) > 0 {
+            return Err(PyRuntimeError::new_err(
+                "Cannot release frame view while a buffer-protocol view is still alive",
+            ));
+        }
+        if self.buffer.take().is_some() {
+            self.ring.outstanding.fetch_sub(1, Ordering::AcqRel);
+        }
+        Ok(())
+    }
+
+    /// Zero-copy buffer-protocol export, e.g. for `np.frombuffer(view)`.
+    /// Reads the pool buffer in place instead of `as_numpy`'s full copy.
+    unsafe fn __getbuffer__(
+        slf: PyRefMut<Self>,
+        view: *mut pyo3::ffi::Py_buffer,
+        flags: std::os::raw::c_int,
+    ) -> PyResult<()> {
+        if view.is_null() {
+            return Err(PyValueError::new_err("View is null"));
+        }
+        if (flags & pyo3::ffi::PyBUF_WRITABLE) != 0 {
+            return Err(PyValueError::new_err(
+                "FrameView only exposes a read-only buffer",
+            ));
+        }
+
+        let tracked = slf
+            .buffer
+            .as_ref()
+            .ok_or_else(|| PyValueError::new_err("Frame view is empty"))?;
+        let len = tracked.len();
+        let ptr = tracked.as_slice().as_ptr() as *mut std::os::raw::c_void;
+
+        (*view).buf = ptr;
+        (*view).len = len as isize;
+        (*view).readonly = 1;
+        (*view).itemsize = 1;
+        (*view).format = if (flags & pyo3::ffi::PyBUF_FORMAT) != 0 {
+            b"B\0".as_ptr() as *mut std::os::raw::c_char
+        } else {
+            ptr::null_mut()
+        };
+        (*view).ndim = 1;
+        (*view).shape = ptr::null_mut();
+        (*view).strides = ptr::null_mut();
+        (*view).suboffsets = ptr::null_mut();
+        (*view).internal = ptr::null_mut();
+
+        slf.export_count.fetch_add(1, Ordering::AcqRel);
+        // Keep the exporting object alive for as long as the view exists.
+        let owner = slf.as_ptr();
+        pyo3::ffi::Py_INCREF(owner);
+        (*view).obj = owner;
+
+        Ok(())
+    }
+
+    unsafe fn __releasebuffer__(slf: PyRefMut<Self>, _view: *mut pyo3::ffi::Py_buffer) {
+        slf.export_count.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+impl Drop for PyFrameView {
+    fn drop(&mut self) {
+        // A live buffer-protocol export holds a reference back to this
+        // object (see `__getbuffer__`), so `drop` only runs once
+        // `export_count` is back to zero — no need to check it here.
+        if self.buffer.take().is_some() {
+            self.ring.outstanding.fetch_sub(1, Ordering::AcqRel);
+        }
+    }
+}
+
+#[pyclass(name = "FrameStream", module = "jarvis_rust_core")]
+pub struct PyFrameStream {
+    ring: Arc<FrameRing>,
+    last_seen: u64,
+}
+
+unsafe impl Send for PyFrameStream {}
+unsafe impl Sync for PyFrameStream {}
+
+#[pymethods]
+impl PyFrameStream {
+    fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    fn __exit__(
+        &mut self,
+        _exc_type: Option<&PyAny>,
+        _exc_value: Option<&PyAny>,
+        _traceback: Option<&PyAny>,
+    ) -> PyResult<bool> {
+        self.close();
+        Ok(false)
+    }
+
+    fn __iter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    /// Poll for the most recently completed frame. Returns `None` — not an
+    /// exception, not a blocking wait — if no new frame has arrived since
+    /// the last pull, or if `max_inflight` pulled frames are still
+    /// outstanding.
+    fn __next__(
+        &mut self,
+        py: Python,
+    ) -> PyResult<pyo3::pyclass::IterNextOutput<PyObject, PyObject>> {
+        // Yield Python `None` explicitly: an `Option::None` returned from
+        // `__next__` would end the iteration with `StopIteration`.
+        let item = match self.next()? {
+            Some(view) => Py::new(py, view)?.into_py(py),
+            None => py.None(),
+        };
+        Ok(pyo3::pyclass::IterNextOutput::Yield(item))
+    }
+
+    fn next(&mut self) -> PyResult<Option<PyFrameView>> {
+        let generation = self.ring.generation.load(Ordering::Acquire);
+        if generation == self.last_seen {
+            return Ok(None);
+        }
+        if self.ring.outstanding.load(Ordering::Acquire) >= self.ring.max_inflight {
+            return Ok(None);
+        }
+
+        let buffer = match self.ring.latest.lock().take() {
+            Some(buffer) => buffer,
+            None => return Ok(None),
+        };
+        self.last_seen = generation;
+        let (width, height, channels) = *self.ring.shape.lock();
+        self.ring.outstanding.fetch_add(1, Ordering::AcqRel);
+
+        Ok(Some(PyFrameView {
+            buffer: Some(buffer),
+            ring: self.ring.clone(),
+            width,
+            height,
+            channels,
+            export_count: AtomicUsize::new(0),
+        }))
+    }
+
+    /// Stop the background capture worker and drain the ring.
+    fn close(&mut self) {
+        self.ring.running.store(false, Ordering::Release);
+        self.ring.latest.lock().take();
+    }
+}
+
+impl Drop for PyFrameStream {
+    fn drop(&mut self) {
+        self.close();
+    }
 }
 
 // ============================================================================
@@ -189,6 +639,57 @@ impl PyScreenCapture {
 pub struct PyMetalAccelerator {
     inner: Arc<MetalAccelerator>,
     runtime: Arc<tokio::runtime::Runtime>,
+    /// MSL translations of previously-compiled WGSL sources, keyed by a
+    /// hash of `(source, entry_point)` so re-registering an unchanged
+    /// kernel skips the WGSL frontend/validator/backend pass.
+    shader_cache: Mutex<HashMap<u64, String>>,
+    /// GPU-side timing samples gathered from command-buffer timestamp
+    /// queries, keyed by shader name.
+    shader_profiles: Mutex<HashMap<String, ShaderProfile>>,
+}
+
+/// Accumulated GPU timestamp-query timing for a single named shader.
+/// `total_gpu_ms`/`calls` track the full history for an exact average;
+/// `recent_gpu_ms` retains a bounded window used to estimate p95 without
+/// letting long-running processes grow this unboundedly.
+#[derive(Default)]
+struct ShaderProfile {
+    calls: u64,
+    total_gpu_ms: f64,
+    recent_gpu_ms: std::collections::VecDeque<f64>,
+}
+
+/// Number of most-recent GPU timing samples kept per shader for the p95
+/// estimate in `get_shader_profile`.
+const SHADER_PROFILE_WINDOW: usize = 512;
+
+impl ShaderProfile {
+    fn record(&mut self, gpu_ms: f64) {
+        self.calls += 1;
+        self.total_gpu_ms += gpu_ms;
+        self.recent_gpu_ms.push_back(gpu_ms);
+        if self.recent_gpu_ms.len() > SHADER_PROFILE_WINDOW {
+            self.recent_gpu_ms.pop_front();
+        }
+    }
+
+    fn avg_gpu_ms(&self) -> f64 {
+        if self.calls == 0 {
+            0.0
+        } else {
+            self.total_gpu_ms / self.calls as f64
+        }
+    }
+
+    fn p95_gpu_ms(&self) -> f64 {
+        if self.recent_gpu_ms.is_empty() {
+            return 0.0;
+        }
+        let mut sorted: Vec<f64> = self.recent_gpu_ms.iter().copied().collect();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let idx = ((sorted.len() as f64) * 0.95).ceil() as usize;
+        sorted[idx.saturating_sub(1).min(sorted.len() - 1)]
+    }
 }
 
 #[cfg(target_os = "macos")]
@@ -213,28 +714,154 @@ impl PyMetalAccelerator {
         Ok(Self {
             inner: Arc::new(accelerator),
             runtime: Arc::new(runtime),
+            shader_cache: Mutex::new(HashMap::new()),
+            shader_profiles: Mutex::new(HashMap::new()),
         })
     }
-    
-    /// Process frame with Metal shader
+
+    /// Compile a WGSL compute kernel at runtime and register it under
+    /// `name` so it becomes usable by `process_frame`. The source is run
+    /// through naga's WGSL frontend, validated as a compute shader, and
+    /// translated to Metal Shading Language — cached here so re-registering
+    /// an unchanged kernel skips the frontend/validator/backend pass again
+    /// — while the original WGSL is what `MetalAccelerator` actually builds
+    /// and caches as a compute pipeline state, since that's what it can
+    /// dispatch. Rejects kernels whose bind-group layout doesn't match the
+    /// expected (input image, output image, uniform params) signature.
+    fn compile_shader(&self, name: &str, source: &str, entry_point: &str) -> PyResult<()> {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        source.hash(&mut hasher);
+        entry_point.hash(&mut hasher);
+        let cache_key = hasher.finish();
+
+        {
+            let mut cache = self.shader_cache.lock();
+            if !cache.contains_key(&cache_key) {
+                let compiled = Self::compile_wgsl_to_msl(source, entry_point)?;
+                cache.insert(cache_key, compiled);
+            }
+        }
+
+        self.inner
+            .register_compute_shader(name, source, entry_point)
+            .map_err(|e| PyRuntimeError::new_err(e.to_string()))
+    }
+
+    /// Parse, validate, and translate a WGSL compute kernel to MSL,
+    /// surfacing any frontend/validation error with its reported span.
+    fn compile_wgsl_to_msl(source: &str, entry_point: &str) -> PyResult<String> {
+        let module = naga::front::wgsl::parse_str(source).map_err(|e| {
+            PyValueError::new_err(format!(
+                "WGSL parse error: {}",
+                e.emit_to_string(source)
+            ))
+        })?;
+
+        let info = naga::valid::Validator::new(
+            naga::valid::ValidationFlags::all(),
+            naga::valid::Capabilities::empty(),
+        )
+        .validate(&module)
+        .map_err(|e| PyValueError::new_err(format!("WGSL validation error: {}", e)))?;
+
+        let has_entry = module
+            .entry_points
+            .iter()
+            .any(|ep| ep.name == entry_point && ep.stage == naga::ShaderStage::Compute);
+        if !has_entry {
+            return Err(PyValueError::new_err(format!(
+                "entry point `{}` not found, or not a compute stage",
+                entry_point
+            )));
+        }
+
+        Self::validate_bind_group_layout(&module)?;
+
+        let options = naga::back::msl::Options::default();
+        let pipeline_options = naga::back::msl::PipelineOptions {
+            allow_and_force_point_size: false,
+        };
+        let (msl, _translation_info) =
+            naga::back::msl::write_string(&module, &info, &options, &pipeline_options)
+                .map_err(|e| PyRuntimeError::new_err(format!("MSL codegen failed: {}", e)))?;
+
+        Ok(msl)
+    }
+
+    /// Require exactly the (input image, output image, uniform params)
+    /// binding signature `process_frame` expects: two storage
+    /// textures/buffers plus one uniform block.
+    fn validate_bind_group_layout(module: &naga::Module) -> PyResult<()> {
+        let bound: Vec<&naga::GlobalVariable> = module
+            .global_variables
+            .iter()
+            .filter(|(_, var)| var.binding.is_some())
+            .map(|(_, var)| var)
+            .collect();
+        let bound_count = bound.len();
+
+        if bound_count != 3 {
+            return Err(PyValueError::new_err(format!(
+                "expected 3 bound resources (input image, output image, uniform params), found {}",
+                bound_count
+            )));
+        }
+
+        let storage_count = bound
+            .iter()
+            .filter(|var| matches!(var.space, naga::AddressSpace::Storage { .. } | naga::AddressSpace::Handle))
+            .count();
+        let uniform_count = bound
+            .iter()
+            .filter(|var| matches!(var.space, naga::AddressSpace::Uniform))
+            .count();
+        if storage_count != 2 || uniform_count != 1 {
+            return Err(PyValueError::new_err(
+                "expected two storage textures/buffers and one uniform block",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Process frame with Metal shader. When `label` is given, it is set
+    /// as the debug label on the underlying command buffer/encoder so the
+    /// dispatch is identifiable in GPU frame-capture tools, and the
+    /// GPU-side duration reported by the command buffer's timestamp
+    /// queries is accumulated into `get_shader_profile()` under
+    /// `shader_name`.
+    #[pyo3(signature = (data, shader_name, label=None))]
     fn process_frame(
         &self,
         py: Python,
         data: &PyArray3<u8>,
         shader_name: &str,
+        label: Option<&str>,
     ) -> PyResult<Py<PyArray3<u8>>> {
         let input_slice = unsafe { data.as_slice()? };
         let shape = data.shape();
         let (height, width, channels) = (shape[0] as u32, shape[1] as u32, shape[2]);
-        
+
         let accel = self.inner.clone();
         let shader = shader_name.to_string();
         let input_vec = input_slice.to_vec();
-        
-        let result = self.runtime.block_on(async move {
-            accel.process_frame(&input_vec, &shader, width, height).await
+        let debug_label = label.map(|l| l.to_string());
+
+        let (result, gpu_ms) = self.runtime.block_on(async move {
+            accel
+                .process_frame_timed(&input_vec, &shader, width, height, channels as u32, debug_label.as_deref())
+                .await
         }).map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
-        
+
+        self.shader_profiles
+            .lock()
+            .entry(shader_name.to_string())
+            .or_default()
+            .record(gpu_ms);
+
         // Convert back to numpy
         let output_shape = [height as usize, width as usize, channels];
         let array = unsafe { PyArray3::new(py, output_shape, false) };
@@ -243,6 +870,25 @@ impl PyMetalAccelerator {
         }
         Ok(array.to_owned())
     }
+
+    /// Per-shader GPU timestamp-query profile, keyed by shader name, each
+    /// mapping to `{calls, total_gpu_ms, avg_gpu_ms, p95_gpu_ms}`.
+    fn get_shader_profile(&self) -> PyResult<HashMap<String, HashMap<String, PyObject>>> {
+        let profiles = self.shader_profiles.lock();
+
+        Python::with_gil(|py| {
+            let mut out = HashMap::new();
+            for (name, profile) in profiles.iter() {
+                let mut entry = HashMap::new();
+                entry.insert("calls".to_string(), profile.calls.to_object(py));
+                entry.insert("total_gpu_ms".to_string(), profile.total_gpu_ms.to_object(py));
+                entry.insert("avg_gpu_ms".to_string(), profile.avg_gpu_ms().to_object(py));
+                entry.insert("p95_gpu_ms".to_string(), profile.p95_gpu_ms().to_object(py));
+                out.insert(name.clone(), entry);
+            }
+            Ok(out)
+        })
+    }
     
     /// Compute frame difference
     fn frame_difference(
@@ -270,7 +916,71 @@ impl PyMetalAccelerator {
         // Convert to PyArray
         Ok(PyArray3::from_owned_array(py, result).to_owned())
     }
-    
+
+    /// Compute consecutive-frame differences for a stack of same-shaped
+    /// frames in a single GPU submission. Equivalent to calling
+    /// `frame_difference` pairwise across the stack, but uploads the
+    /// whole stack once and runs every diff in one command buffer
+    /// instead of N separate dispatches and host<->device round trips.
+    fn frame_difference_batch(
+        &self,
+        py: Python,
+        frames: Vec<&PyArray3<u8>>,
+    ) -> PyResult<Vec<Py<PyArray3<f32>>>> {
+        if frames.len() < 2 {
+            return Err(PyValueError::new_err(
+                "frame_difference_batch requires at least 2 frames",
+            ));
+        }
+
+        let reference_shape = frames[0].shape();
+        for frame in &frames[1..] {
+            if frame.shape() != reference_shape {
+                return Err(PyValueError::new_err("All frames must share the same shape"));
+            }
+        }
+
+        let views: Vec<_> = frames.iter().map(|f| unsafe { f.as_array() }).collect();
+        let accel = self.inner.clone();
+
+        let diffs = self.runtime.block_on(async move {
+            accel.frame_difference_batch(&views).await
+        }).map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+
+        diffs
+            .into_iter()
+            .map(|diff| Ok(PyArray3::from_owned_array(py, diff).to_owned()))
+            .collect()
+    }
+
+    /// Per-pixel temporal variance/activity accumulated across a sliding
+    /// window of same-shaped frames, computed in one GPU submission.
+    /// Gives motion-detection pipelines a single activity signal instead
+    /// of diffing every pair on the host and reducing them there.
+    fn motion_energy(&self, py: Python, frames: Vec<&PyArray3<u8>>) -> PyResult<Py<PyArray2<f32>>> {
+        if frames.len() < 2 {
+            return Err(PyValueError::new_err(
+                "motion_energy requires at least 2 frames",
+            ));
+        }
+
+        let reference_shape = frames[0].shape();
+        for frame in &frames[1..] {
+            if frame.shape() != reference_shape {
+                return Err(PyValueError::new_err("All frames must share the same shape"));
+            }
+        }
+
+        let views: Vec<_> = frames.iter().map(|f| unsafe { f.as_array() }).collect();
+        let accel = self.inner.clone();
+
+        let energy = self.runtime.block_on(async move {
+            accel.motion_energy(&views).await
+        }).map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+
+        Ok(PyArray2::from_owned_array(py, energy).to_owned())
+    }
+
     /// Get performance statistics
     fn get_stats(&self) -> PyResult<HashMap<String, PyObject>> {
         let stats = self.inner.stats();
@@ -285,6 +995,422 @@ impl PyMetalAccelerator {
     }
 }
 
+// ============================================================================
+// CROSS-PLATFORM GPU ACCELERATOR (wgpu: Vulkan/DX12/Metal/GL)
+// ============================================================================
+
+/// Number of invocations per workgroup for every `GpuAccelerator` shader;
+/// dispatch size is `ceil(width * height / GPU_WORKGROUP_SIZE)`.
+const GPU_WORKGROUP_SIZE: u32 = 64;
+
+/// RGBA-packed `u8` input -> luminance-weighted grayscale `u32` output.
+const GPU_GRAYSCALE_SHADER: &str = concat!(
+    "struct Params {\n    width: u32,\n    height: u32,\n};\n\n",
+    "@group(0) @binding(0) var<storage, read> input_pixels: array<u32>;\n",
+    "@group(0) @binding(1) var<storage, read_write> output_gray: array<u32>;\n",
+    "@group(0) @binding(2) var<uniform> params: Params;\n\n",
+    "@compute @workgroup_size(64)\n",
+    "fn main(@builtin(global_invocation_id) gid: vec3<u32>) {\n",
+    "    let idx = gid.x;\n",
+    "    if (idx >= params.width * params.height) {\n",
+    "        return;\n",
+    "    }\n",
+    "    let packed = input_pixels[idx];\n",
+    "    let r = f32(packed & 0xffu);\n",
+    "    let g = f32((packed >> 8u) & 0xffu);\n",
+    "    let b = f32((packed >> 16u) & 0xffu);\n",
+    "    output_gray[idx] = u32(0.299 * r + 0.587 * g + 0.114 * b);\n",
+    "}\n",
+);
+
+/// 3x3 Sobel pass over the grayscale buffer, reduced to an edge count via
+/// an atomic counter instead of writing a full gradient image back out.
+const GPU_SOBEL_EDGE_DENSITY_SHADER: &str = concat!(
+    "struct Params {\n    width: u32,\n    height: u32,\n};\n\n",
+    "@group(0) @binding(0) var<storage, read> gray: array<u32>;\n",
+    "@group(0) @binding(1) var<storage, read_write> edge_count: atomic<u32>;\n",
+    "@group(0) @binding(2) var<uniform> params: Params;\n\n",
+    "fn sample(x: i32, y: i32) -> i32 {\n",
+    "    let cx = clamp(x, 0, i32(params.width) - 1);\n",
+    "    let cy = clamp(y, 0, i32(params.height) - 1);\n",
+    "    return i32(gray[u32(cy) * params.width + u32(cx)]);\n",
+    "}\n\n",
+    "@compute @workgroup_size(64)\n",
+    "fn main(@builtin(global_invocation_id) gid: vec3<u32>) {\n",
+    "    let idx = gid.x;\n",
+    "    if (idx >= params.width * params.height) {\n",
+    "        return;\n",
+    "    }\n",
+    "    let x = i32(idx % params.width);\n",
+    "    let y = i32(idx / params.width);\n\n",
+    "    let gx = sample(x - 1, y - 1) + 2 * sample(x - 1, y) + sample(x - 1, y + 1)\n",
+    "        - sample(x + 1, y - 1) - 2 * sample(x + 1, y) - sample(x + 1, y + 1);\n",
+    "    let gy = sample(x - 1, y - 1) + 2 * sample(x, y - 1) + sample(x + 1, y - 1)\n",
+    "        - sample(x - 1, y + 1) - 2 * sample(x, y + 1) - sample(x + 1, y + 1);\n\n",
+    "    if (abs(gx) + abs(gy) > 128) {\n",
+    "        atomicAdd(&edge_count, 1u);\n",
+    "    }\n",
+    "}\n",
+);
+
+/// 256-bin histogram of the grayscale buffer, used for the entropy/mean/
+/// std-dev computed in `analyze_texture`.
+const GPU_HISTOGRAM_SHADER: &str = concat!(
+    "struct Params {\n    width: u32,\n    height: u32,\n};\n\n",
+    "@group(0) @binding(0) var<storage, read> gray: array<u32>;\n",
+    "@group(0) @binding(1) var<storage, read_write> histogram: array<atomic<u32>, 256>;\n",
+    "@group(0) @binding(2) var<uniform> params: Params;\n\n",
+    "@compute @workgroup_size(64)\n",
+    "fn main(@builtin(global_invocation_id) gid: vec3<u32>) {\n",
+    "    let idx = gid.x;\n",
+    "    if (idx >= params.width * params.height) {\n",
+    "        return;\n",
+    "    }\n",
+    "    atomicAdd(&histogram[gray[idx]], 1u);\n",
+    "}\n",
+);
+
+/// Cross-platform GPU acceleration for the texture/edge analysis stages,
+/// built on `wgpu` (Vulkan/DX12/Metal/GL) rather than Metal directly, so
+/// `register_python_module` can expose it unconditionally instead of
+/// gating acceleration on macOS the way `MetalAccelerator` is.
+#[pyclass(name = "GpuAccelerator", module = "jarvis_rust_core")]
+pub struct PyGpuAccelerator {
+    runtime: Arc<tokio::runtime::Runtime>,
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    adapter_name: String,
+    backend: String,
+    grayscale_pipeline: wgpu::ComputePipeline,
+    sobel_pipeline: wgpu::ComputePipeline,
+    histogram_pipeline: wgpu::ComputePipeline,
+}
+
+unsafe impl Send for PyGpuAccelerator {}
+unsafe impl Sync for PyGpuAccelerator {}
+
+impl PyGpuAccelerator {
+    fn compile_pipeline(device: &wgpu::Device, label: &str, source: &str) -> wgpu::ComputePipeline {
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some(label),
+            source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(source)),
+        });
+
+        device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some(label),
+            layout: None,
+            module: &module,
+            entry_point: "main",
+        })
+    }
+
+    /// Pack image bytes one `u32` per pixel (RGBA byte order), expanding
+    /// narrower channel counts the same way the CPU path interprets them.
+    fn pack_rgba(data: &[u8], channels: usize) -> Vec<u32> {
+        if channels == 0 {
+            return Vec::new();
+        }
+        data.chunks_exact(channels)
+            .map(|chunk| {
+                let (r, g, b, a) = match channels {
+                    1 => (chunk[0], chunk[0], chunk[0], 255u8),
+                    2 => (chunk[0], chunk[0], chunk[0], chunk[1]),
+                    3 => (chunk[0], chunk[1], chunk[2], 255u8),
+                    _ => (chunk[0], chunk[1], chunk[2], chunk[3]),
+                };
+                u32::from_le_bytes([r, g, b, a])
+            })
+            .collect()
+    }
+
+    fn read_buffer(&self, buffer: &wgpu::Buffer, size: u64) -> PyResult<Vec<u8>> {
+        let slice = buffer.slice(..size);
+        let (tx, rx) = std::sync::mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = tx.send(result);
+        });
+        self.device.poll(wgpu::Maintain::Wait);
+
+        rx.recv()
+            .map_err(|_| PyRuntimeError::new_err("GPU buffer map channel closed unexpectedly"))?
+            .map_err(|e| PyRuntimeError::new_err(format!("GPU buffer map failed: {:?}", e)))?;
+
+        let data = slice.get_mapped_range().to_vec();
+        buffer.unmap();
+        Ok(data)
+    }
+
+    /// Upload `image` in one staging copy and run grayscale -> Sobel
+    /// edge-density -> histogram in a single command buffer, returning
+    /// the 256-bin histogram and the edge-pixel count.
+    fn run_compute(&self, image: &ImageData) -> PyResult<([u32; 256], u32)> {
+        let width = image.width;
+        let height = image.height;
+        let pixel_count = (width as usize) * (height as usize);
+        if pixel_count == 0 {
+            return Ok(([0u32; 256], 0));
+        }
+
+        let packed = Self::pack_rgba(image.as_slice(), image.channels as usize);
+        let input_bytes: Vec<u8> = packed.iter().flat_map(|v| v.to_le_bytes()).collect();
+
+        let mut params_bytes = Vec::with_capacity(8);
+        params_bytes.extend_from_slice(&width.to_le_bytes());
+        params_bytes.extend_from_slice(&height.to_le_bytes());
+
+        let device = &self.device;
+
+        let input_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("gpu_accelerator_input"),
+            size: input_bytes.len() as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        self.queue.write_buffer(&input_buffer, 0, &input_bytes);
+
+        let gray_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("gpu_accelerator_gray"),
+            size: (pixel_count * 4) as u64,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_SRC
+                | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let edge_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("gpu_accelerator_edge_count"),
+            size: 4,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_SRC
+                | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        self.queue.write_buffer(&edge_buffer, 0, &0u32.to_le_bytes());
+
+        let histogram_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("gpu_accelerator_histogram"),
+            size: 256 * 4,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_SRC
+                | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        self.queue.write_buffer(&histogram_buffer, 0, &vec![0u8; 256 * 4]);
+
+        let params_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("gpu_accelerator_params"),
+            size: params_bytes.len() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        self.queue.write_buffer(&params_buffer, 0, &params_bytes);
+
+        let grayscale_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("gpu_accelerator_grayscale_bind_group"),
+            layout: &self.grayscale_pipeline.get_bind_group_layout(0),
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: input_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 1, resource: gray_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 2, resource: params_buffer.as_entire_binding() },
+            ],
+        });
+        let sobel_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("gpu_accelerator_sobel_bind_group"),
+            layout: &self.sobel_pipeline.get_bind_group_layout(0),
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: gray_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 1, resource: edge_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 2, resource: params_buffer.as_entire_binding() },
+            ],
+        });
+        let histogram_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("gpu_accelerator_histogram_bind_group"),
+            layout: &self.histogram_pipeline.get_bind_group_layout(0),
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: gray_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 1, resource: histogram_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 2, resource: params_buffer.as_entire_binding() },
+            ],
+        });
+
+        let workgroups = (pixel_count as u32 + GPU_WORKGROUP_SIZE - 1) / GPU_WORKGROUP_SIZE;
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("gpu_accelerator_encoder"),
+        });
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("grayscale_pass"),
+                timestamp_writes: None,
+            });
+            pass.set_pipeline(&self.grayscale_pipeline);
+            pass.set_bind_group(0, &grayscale_bind_group, &[]);
+            pass.dispatch_workgroups(workgroups, 1, 1);
+        }
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("sobel_edge_density_pass"),
+                timestamp_writes: None,
+            });
+            pass.set_pipeline(&self.sobel_pipeline);
+            pass.set_bind_group(0, &sobel_bind_group, &[]);
+            pass.dispatch_workgroups(workgroups, 1, 1);
+        }
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("histogram_pass"),
+                timestamp_writes: None,
+            });
+            pass.set_pipeline(&self.histogram_pipeline);
+            pass.set_bind_group(0, &histogram_bind_group, &[]);
+            pass.dispatch_workgroups(workgroups, 1, 1);
+        }
+
+        let edge_readback = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("gpu_accelerator_edge_readback"),
+            size: 4,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let histogram_readback = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("gpu_accelerator_histogram_readback"),
+            size: 256 * 4,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        encoder.copy_buffer_to_buffer(&edge_buffer, 0, &edge_readback, 0, 4);
+        encoder.copy_buffer_to_buffer(&histogram_buffer, 0, &histogram_readback, 0, 256 * 4);
+
+        self.queue.submit(Some(encoder.finish()));
+
+        let edge_bytes = self.read_buffer(&edge_readback, 4)?;
+        let edge_count = u32::from_le_bytes(edge_bytes.try_into().unwrap());
+
+        let histogram_bytes = self.read_buffer(&histogram_readback, 256 * 4)?;
+        let mut histogram = [0u32; 256];
+        for (bin, chunk) in histogram.iter_mut().zip(histogram_bytes.chunks_exact(4)) {
+            *bin = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+
+        Ok((histogram, edge_count))
+    }
+}
+
+#[pymethods]
+impl PyGpuAccelerator {
+    #[new]
+    fn new() -> PyResult<Self> {
+        let runtime = tokio::runtime::Runtime::new()
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to create runtime: {}", e)))?;
+
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::default());
+
+        let adapter = runtime
+            .block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                compatible_surface: None,
+                force_fallback_adapter: false,
+            }))
+            .ok_or_else(|| PyRuntimeError::new_err("No compatible wgpu adapter found"))?;
+
+        let (device, queue) = runtime
+            .block_on(adapter.request_device(
+                &wgpu::DeviceDescriptor {
+                    label: Some("jarvis_gpu_accelerator"),
+                    features: wgpu::Features::empty(),
+                    limits: wgpu::Limits::default(),
+                },
+                None,
+            ))
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to acquire wgpu device: {}", e)))?;
+
+        let info = adapter.get_info();
+
+        let grayscale_pipeline = Self::compile_pipeline(&device, "grayscale", GPU_GRAYSCALE_SHADER);
+        let sobel_pipeline =
+            Self::compile_pipeline(&device, "sobel_edge_density", GPU_SOBEL_EDGE_DENSITY_SHADER);
+        let histogram_pipeline = Self::compile_pipeline(&device, "histogram", GPU_HISTOGRAM_SHADER);
+
+        Ok(Self {
+            runtime: Arc::new(runtime),
+            device,
+            queue,
+            adapter_name: info.name,
+            backend: format!("{:?}", info.backend),
+            grayscale_pipeline,
+            sobel_pipeline,
+            histogram_pipeline,
+        })
+    }
+
+    /// Backend (Vulkan/Metal/Dx12/Gl) and adapter name wgpu chose.
+    fn device_info(&self) -> PyResult<HashMap<String, PyObject>> {
+        Python::with_gil(|py| {
+            let mut map = HashMap::new();
+            map.insert("backend".to_string(), self.backend.to_object(py));
+            map.insert("adapter_name".to_string(), self.adapter_name.to_object(py));
+            Ok(map)
+        })
+    }
+
+    /// Edge density via a GPU Sobel pass reduced through an atomic counter,
+    /// rather than diffing a gradient image back on the host.
+    fn calculate_edge_density(&self, image: PyReadonlyArrayDyn<u8>) -> PyResult<f64> {
+        let image = numpy_to_image(image)?;
+        let pixel_count = (image.width as u64) * (image.height as u64);
+        if pixel_count == 0 {
+            return Ok(0.0);
+        }
+
+        let (_, edge_count) = self.run_compute(&image)?;
+        Ok(edge_count as f64 / pixel_count as f64)
+    }
+
+    /// Mean/std-dev/entropy/edge-density from a single GPU submission:
+    /// grayscale -> Sobel edge-density -> 256-bin histogram.
+    fn analyze_texture(&self, image: PyReadonlyArrayDyn<u8>) -> PyResult<HashMap<String, f64>> {
+        let image = numpy_to_image(image)?;
+        let pixel_count = (image.width as u64) * (image.height as u64);
+        if pixel_count == 0 {
+            return Ok(HashMap::new());
+        }
+
+        let (histogram, edge_count) = self.run_compute(&image)?;
+        let total = pixel_count as f64;
+
+        let mean = histogram
+            .iter()
+            .enumerate()
+            .map(|(value, count)| value as f64 * *count as f64)
+            .sum::<f64>()
+            / total;
+        let variance = histogram
+            .iter()
+            .enumerate()
+            .map(|(value, count)| {
+                let d = value as f64 - mean;
+                d * d * *count as f64
+            })
+            .sum::<f64>()
+            / total;
+        let entropy = histogram
+            .iter()
+            .filter(|count| **count > 0)
+            .map(|count| {
+                let p = *count as f64 / total;
+                -p * p.log2()
+            })
+            .sum::<f64>();
+
+        let mut result = HashMap::new();
+        result.insert("mean".to_string(), mean);
+        result.insert("std_dev".to_string(), variance.sqrt());
+        result.insert("entropy".to_string(), entropy);
+        result.insert("edge_density".to_string(), edge_count as f64 / total);
+        Ok(result)
+    }
+}
+
 // ============================================================================
 // THREAD-SAFE MEMORY MANAGER FOR PYTHON
 // ============================================================================
@@ -611,6 +1737,17 @@ impl PyRustRuntimeManager {
 #[pyclass(name = "RustTrackedBuffer", module = "jarvis_rust_core")]
 pub struct PyRustTrackedBuffer {
     buffer: Arc<Mutex<Option<TrackedBuffer>>>,
+    readonly: Mutex<bool>,
+    /// Optional 2D/3D shape (e.g. `[height, width, channels]`) used when
+    /// exporting this buffer so numpy can reconstruct a `PyArray3` view
+    /// directly, instead of a flat `PyArray1`.
+    shape: Mutex<Option<Vec<usize>>>,
+    /// Count of live buffer-protocol exports (e.g. `np.frombuffer` views).
+    /// `release` refuses to free the underlying buffer while this is
+    /// nonzero, and each live export holds a reference to this object so
+    /// it cannot be dropped meanwhile, mirroring the invariant that a
+    /// mapped region stays valid until all consumers are done with it.
+    export_count: AtomicUsize,
 }
 
 unsafe impl Send for PyRustTrackedBuffer {}
@@ -618,6 +1755,9 @@ unsafe impl Sync for PyRustTrackedBuffer {}
 
 #[pymethods]
 impl PyRustTrackedBuffer {
+    /// Copying accessor, kept for callers that don't need a zero-copy
+    /// view. Prefer `np.frombuffer(buf)` (backed by `__getbuffer__`) on
+    /// the hot path.
     fn as_numpy(&self, py: Python<'_>) -> PyResult<Py<PyArray1<u8>>> {
         let guard = self.buffer.lock();
         let tracked = guard
@@ -642,9 +1782,140 @@ impl PyRustTrackedBuffer {
         Ok(tracked.len())
     }
 
-    fn release(&self) {
-        let mut guard = self.buffer.lock();
-        *guard = None;
+    /// Mark this buffer read-only for future buffer-protocol exports.
+    fn set_readonly(&self, readonly: bool) {
+        *self.readonly.lock() = readonly;
+    }
+
+    /// Set (or clear) the 2D/3D shape reported to `__getbuffer__`, so
+    /// `np.frombuffer(buf).reshape(...)` isn't needed by the caller.
+    #[pyo3(signature = (shape=None))]
+    fn set_shape(&self, shape: Option<Vec<usize>>) -> PyResult<()> {
+        if let Some(dims) = &shape {
+            if dims.is_empty() || dims.len() > 3 {
+                return Err(PyValueError::new_err("shape must have 1 to 3 dimensions"));
+            }
+            let tracked_len = self
+                .buffer
+                .lock()
+                .as_ref()
+                .ok_or_else(|| PyValueError::new_err("Buffer already released"))?
+                .len();
+            let product: usize = dims.iter().product();
+            if product != tracked_len {
+                return Err(PyValueError::new_err(format!(
+                    "shape {:?} does not match buffer length {}",
+                    dims, tracked_len
+                )));
+            }
+        }
+        *self.shape.lock() = shape;
+        Ok(())
+    }
+
+    /// Return the buffer to the pool. Refuses (raises) while a live
+    /// buffer-protocol export (e.g. an outstanding `memoryview`/numpy
+    /// view) still exists, rather than freeing memory out from under it.
+    fn release(&self) -> PyResult<()> {
+        if self.export_count.load(Ordering::Acquire) > 0 {
+            return Err(PyRuntimeError::new_err(
+                "Cannot release buffer while a buffer-protocol view is still alive",
+            ));
+        }
+        *self.buffer.lock() = None;
+        Ok(())
+    }
+
+    unsafe fn __getbuffer__(
+        slf: PyRefMut<Self>,
+        view: *mut pyo3::ffi::Py_buffer,
+        flags: std::os::raw::c_int,
+    ) -> PyResult<()> {
+        if view.is_null() {
+            return Err(PyValueError::new_err("View is null"));
+        }
+
+        let readonly = *slf.readonly.lock();
+        if readonly && (flags & pyo3::ffi::PyBUF_WRITABLE) != 0 {
+            return Err(PyValueError::new_err(
+                "Buffer is read-only but a writable view was requested",
+            ));
+        }
+
+        let mut guard = slf.buffer.lock();
+        let tracked = guard
+            .as_mut()
+            .ok_or_else(|| PyValueError::new_err("Buffer already released"))?;
+        let len = tracked.len();
+        let ptr = tracked.as_slice().as_ptr() as *mut std::os::raw::c_void;
+        drop(guard);
+
+        let shape_dims = slf.shape.lock().clone();
+
+        (*view).buf = ptr;
+        (*view).len = len as isize;
+        (*view).readonly = readonly as std::os::raw::c_int;
+        (*view).itemsize = 1;
+        (*view).format = if (flags & pyo3::ffi::PyBUF_FORMAT) != 0 {
+            b"B\0".as_ptr() as *mut std::os::raw::c_char
+        } else {
+            ptr::null_mut()
+        };
+
+        match shape_dims {
+            Some(dims) if (flags & pyo3::ffi::PyBUF_ND) != 0 => {
+                let shape_box: Box<[isize]> =
+                    dims.iter().map(|d| *d as isize).collect::<Vec<_>>().into_boxed_slice();
+                (*view).ndim = shape_box.len() as std::os::raw::c_int;
+                (*view).shape = Box::into_raw(shape_box) as *mut isize;
+                (*view).strides = ptr::null_mut();
+                if (flags & pyo3::ffi::PyBUF_STRIDES) != 0 {
+                    let mut strides = vec![0isize; dims.len()];
+                    let mut acc = 1isize;
+                    for i in (0..dims.len()).rev() {
+                        strides[i] = acc;
+                        acc *= dims[i] as isize;
+                    }
+                    (*view).strides = Box::into_raw(strides.into_boxed_slice()) as *mut isize;
+                }
+            }
+            _ => {
+                (*view).ndim = 1;
+                (*view).shape = ptr::null_mut();
+                (*view).strides = ptr::null_mut();
+            }
+        }
+        (*view).suboffsets = ptr::null_mut();
+        (*view).internal = ptr::null_mut();
+
+        slf.export_count.fetch_add(1, Ordering::AcqRel);
+        // Keep the exporting object alive for as long as the view exists.
+        let owner = slf.as_ptr();
+        pyo3::ffi::Py_INCREF(owner);
+        (*view).obj = owner;
+
+        Ok(())
+    }
+
+    unsafe fn __releasebuffer__(slf: PyRefMut<Self>, view: *mut pyo3::ffi::Py_buffer) {
+        if view.is_null() {
+            return;
+        }
+        if !(*view).shape.is_null() {
+            let ndim = (*view).ndim as usize;
+            drop(Box::from_raw(std::slice::from_raw_parts_mut(
+                (*view).shape,
+                ndim,
+            )));
+        }
+        if !(*view).strides.is_null() {
+            let ndim = (*view).ndim as usize;
+            drop(Box::from_raw(std::slice::from_raw_parts_mut(
+                (*view).strides,
+                ndim,
+            )));
+        }
+        slf.export_count.fetch_sub(1, Ordering::AcqRel);
     }
 }
 
@@ -675,6 +1946,9 @@ impl PyRustAdvancedMemoryPool {
 
         Ok(PyRustTrackedBuffer {
             buffer: Arc::new(Mutex::new(Some(tracked))),
+            readonly: Mutex::new(false),
+            shape: Mutex::new(None),
+            export_count: AtomicUsize::new(0),
         })
     }
 
@@ -799,12 +2073,565 @@ fn quantize_model_weights(weights: PyReadonlyArray2<f32>) -> PyResult<Vec<i8>> {
         .collect())
 }
 
+/// Min/max over the finite values of `values`, ignoring NaN/Inf. Returns
+/// `(0.0, 0.0)` if there are no finite values at all.
+fn finite_min_max(values: &[f32]) -> (f32, f32) {
+    let mut min = f32::INFINITY;
+    let mut max = f32::NEG_INFINITY;
+    for value in values {
+        if value.is_finite() {
+            min = min.min(*value);
+            max = max.max(*value);
+        }
+    }
+    if min.is_finite() && max.is_finite() {
+        (min, max)
+    } else {
+        (0.0, 0.0)
+    }
+}
+
+/// Convert to IEEE-754 binary16, returned as raw bits. No external
+/// half-float crate on hand, so this is a from-scratch conversion: works
+/// in `f64` to avoid intermediate rounding, rounds the mantissa to its
+/// 10-bit width with ties-to-even (mirroring `encode_fp8`), drops into a
+/// subnormal encoding when the unbiased exponent underflows binary16's
+/// minimum normal exponent instead of flushing to zero, and overflow
+/// maps to +/-infinity.
+fn f32_to_f16_bits(value: f32) -> u16 {
+    const MANTISSA_BITS: i32 = 10;
+    const BIAS: i32 = 15;
+    const MIN_NORMAL_EXP: i32 = 1 - BIAS;
+    const EXP_MAX: i32 = 0x1f;
+
+    let sign = ((value.to_bits() >> 16) & 0x8000) as u16;
+
+    if value.is_nan() {
+        return sign | 0x7e00;
+    }
+    if value.is_infinite() {
+        return sign | 0x7c00;
+    }
+
+    let magnitude = (value as f64).abs();
+    if magnitude == 0.0 {
+        return sign;
+    }
+
+    let unbiased_exp = magnitude.log2().floor() as i32;
+
+    let (mut biased_exp, mut mantissa) = if unbiased_exp < MIN_NORMAL_EXP {
+        let scale = 2f64.powi(MIN_NORMAL_EXP - MANTISSA_BITS);
+        (0i32, round_half_even(magnitude / scale) as i64)
+    } else {
+        let frac = magnitude / 2f64.powi(unbiased_exp) - 1.0;
+        let mantissa = round_half_even(frac * 2f64.powi(MANTISSA_BITS)) as i64;
+        (unbiased_exp + BIAS, mantissa)
+    };
+
+    let mantissa_max = 1i64 << MANTISSA_BITS;
+    if mantissa >= mantissa_max {
+        mantissa -= mantissa_max;
+        biased_exp += 1;
+    }
+
+    if biased_exp >= EXP_MAX {
+        return sign | 0x7c00;
+    }
+
+    sign | ((biased_exp as u16) << MANTISSA_BITS) | mantissa as u16
+}
+
+/// Richer quantization schemes that, unlike `quantize_model_weights`,
+/// return the metadata needed to dequantize: `{quantized, scheme, scales,
+/// zero_points}`. `scheme` is one of `"symmetric_int8"`,
+/// `"asymmetric_uint8"`, `"per_channel_int8"`, or `"float16"`.
+///
+/// All-zero (or all-non-finite) tensors/rows quantize to zeros with
+/// `scale = 1.0` rather than dividing by zero; NaN/Inf inputs are
+/// skipped when computing a scheme's min/max but still quantize to `0`
+/// (or the zero-point, for the asymmetric scheme); `float16` instead
+/// carries them through as half-precision NaN/infinity.
+#[pyfunction]
+#[pyo3(signature = (weights, scheme="symmetric_int8"))]
+fn quantize_model_weights_ex(
+    py: Python<'_>,
+    weights: PyReadonlyArray2<f32>,
+    scheme: &str,
+) -> PyResult<HashMap<String, PyObject>> {
+    let array = weights.as_array();
+    let mut result = HashMap::new();
+    result.insert("scheme".to_string(), scheme.to_object(py));
+
+    match scheme {
+        "symmetric_int8" => {
+            let values: Vec<f32> = array.iter().copied().collect();
+            let max_abs = values
+                .iter()
+                .filter(|v| v.is_finite())
+                .fold(0.0_f32, |acc, v| acc.max(v.abs()));
+            let scale = if max_abs == 0.0 { 1.0 } else { max_abs / 127.0 };
+
+            let quantized: Vec<i8> = values
+                .iter()
+                .map(|value| {
+                    if !value.is_finite() || max_abs == 0.0 {
+                        0
+                    } else {
+                        (value / scale).round().clamp(-128.0, 127.0) as i8
+                    }
+                })
+                .collect();
+
+            result.insert("quantized".to_string(), quantized.to_object(py));
+            result.insert("scales".to_string(), vec![scale].to_object(py));
+            result.insert("zero_points".to_string(), Vec::<i64>::new().to_object(py));
+        }
+        "asymmetric_uint8" => {
+            let values: Vec<f32> = array.iter().copied().collect();
+            let (min, max) = finite_min_max(&values);
+            let scale = if max > min { (max - min) / 255.0 } else { 1.0 };
+            let zero_point = (-min / scale).round().clamp(0.0, 255.0) as i64;
+
+            let quantized: Vec<u8> = values
+                .iter()
+                .map(|value| {
+                    if !value.is_finite() {
+                        zero_point as u8
+                    } else {
+                        ((value / scale).round() as i64 + zero_point).clamp(0, 255) as u8
+                    }
+                })
+                .collect();
+
+            result.insert("quantized".to_string(), quantized.to_object(py));
+            result.insert("scales".to_string(), vec![scale].to_object(py));
+            result.insert("zero_points".to_string(), vec![zero_point].to_object(py));
+        }
+        "per_channel_int8" => {
+            let (rows, cols) = (array.shape()[0], array.shape()[1]);
+            let mut quantized = Vec::with_capacity(rows * cols);
+            let mut scales = Vec::with_capacity(rows);
+
+            for row in array.outer_iter() {
+                let max_abs = row
+                    .iter()
+                    .filter(|v| v.is_finite())
+                    .fold(0.0_f32, |acc, v| acc.max(v.abs()));
+                let scale = if max_abs == 0.0 { 1.0 } else { max_abs / 127.0 };
+                scales.push(scale);
+
+                quantized.extend(row.iter().map(|value| {
+                    if !value.is_finite() || max_abs == 0.0 {
+                        0
+                    } else {
+                        (value / scale).round().clamp(-128.0, 127.0) as i8
+                    }
+                }));
+            }
+
+            result.insert("quantized".to_string(), quantized.to_object(py));
+            result.insert("scales".to_string(), scales.to_object(py));
+            result.insert("zero_points".to_string(), Vec::<i64>::new().to_object(py));
+        }
+        "float16" => {
+            let quantized: Vec<u16> = array.iter().map(|value| f32_to_f16_bits(*value)).collect();
+            result.insert("quantized".to_string(), quantized.to_object(py));
+            result.insert("scales".to_string(), Vec::<f32>::new().to_object(py));
+            result.insert("zero_points".to_string(), Vec::<i64>::new().to_object(py));
+        }
+        other => {
+            return Err(PyValueError::new_err(format!(
+                "unknown quantization scheme: {}",
+                other
+            )));
+        }
+    }
+
+    Ok(result)
+}
+
+/// An 8-bit floating-point format: `exp_bits` exponent bits with the given
+/// `bias`, `mantissa_bits` mantissa bits, and a saturating (no infinity)
+/// `max_normal` magnitude.
+struct Fp8Format {
+    name: &'static str,
+    exp_bits: u32,
+    mantissa_bits: u32,
+    bias: i32,
+    max_normal: f64,
+}
+
+const FP8_E4M3: Fp8Format = Fp8Format {
+    name: "e4m3",
+    exp_bits: 4,
+    mantissa_bits: 3,
+    bias: 7,
+    max_normal: 448.0,
+};
+
+const FP8_E5M2: Fp8Format = Fp8Format {
+    name: "e5m2",
+    exp_bits: 5,
+    mantissa_bits: 2,
+    bias: 15,
+    max_normal: 57344.0,
+};
+
+fn fp8_format(name: &str) -> PyResult<&'static Fp8Format> {
+    match name {
+        "e4m3" => Ok(&FP8_E4M3),
+        "e5m2" => Ok(&FP8_E5M2),
+        other => Err(PyValueError::new_err(format!("unknown fp8 format: {}", other))),
+    }
+}
+
+/// Round-half-to-even, since `f64::round` always rounds ties away from
+/// zero and the fp8 spec calls for ties-to-even on the mantissa.
+fn round_half_even(x: f64) -> f64 {
+    let floor = x.floor();
+    let diff = x - floor;
+    if diff < 0.5 {
+        floor
+    } else if diff > 0.5 {
+        floor + 1.0
+    } else if (floor as i64) % 2 == 0 {
+        floor
+    } else {
+        floor + 1.0
+    }
+}
+
+/// Encode one `f32` weight as an 8-bit float code in `format`: decompose
+/// into sign/exponent/mantissa, round the mantissa to the target width
+/// with ties-to-even, drop into a subnormal encoding when the unbiased
+/// exponent underflows the format's minimum normal exponent, and
+/// saturate (rather than produce an infinity) on overflow.
+fn encode_fp8(value: f32, format: &Fp8Format) -> u8 {
+    let sign_bit: u32 = if value.is_sign_negative() { 1 } else { 0 };
+    let magnitude = (value as f64).abs();
+
+    if value.is_nan() || magnitude == 0.0 {
+        return (sign_bit << 7) as u8;
+    }
+
+    let magnitude = magnitude.min(format.max_normal);
+    let min_normal_exp = 1 - format.bias;
+    let unbiased_exp = magnitude.log2().floor() as i32;
+
+    let (mut biased_exp, mut mantissa) = if unbiased_exp < min_normal_exp {
+        let scale = 2f64.powi(min_normal_exp - format.mantissa_bits as i32);
+        (0i32, round_half_even(magnitude / scale) as i64)
+    } else {
+        let frac = magnitude / 2f64.powi(unbiased_exp) - 1.0;
+        let mantissa = round_half_even(frac * 2f64.powi(format.mantissa_bits as i32)) as i64;
+        (unbiased_exp + format.bias, mantissa)
+    };
+
+    let mantissa_max = 1i64 << format.mantissa_bits;
+    if mantissa >= mantissa_max {
+        mantissa -= mantissa_max;
+        biased_exp += 1;
+    }
+
+    let exp_max = (1i32 << format.exp_bits) - 1;
+    if biased_exp > exp_max {
+        biased_exp = exp_max;
+        mantissa = mantissa_max - 1;
+    }
+
+    ((sign_bit << 7) | ((biased_exp as u32) << format.mantissa_bits) | mantissa as u32) as u8
+}
+
+/// Decode an 8-bit float code produced by [`encode_fp8`] back to `f32`.
+fn decode_fp8(code: u8, format: &Fp8Format) -> f32 {
+    let sign: f64 = if code & 0x80 != 0 { -1.0 } else { 1.0 };
+    let exp_max = (1u32 << format.exp_bits) - 1;
+    let biased_exp = ((code >> format.mantissa_bits) as u32) & exp_max;
+    let mantissa_mask = (1u32 << format.mantissa_bits) - 1;
+    let mantissa = (code as u32) & mantissa_mask;
+
+    let magnitude = if biased_exp == 0 {
+        if mantissa == 0 {
+            0.0
+        } else {
+            let min_normal_exp = 1 - format.bias;
+            mantissa as f64 * 2f64.powi(min_normal_exp - format.mantissa_bits as i32)
+        }
+    } else {
+        let unbiased_exp = biased_exp as i32 - format.bias;
+        (1.0 + mantissa as f64 / (1u32 << format.mantissa_bits) as f64) * 2f64.powi(unbiased_exp)
+    };
+
+    (sign * magnitude) as f32
+}
+
+/// FP8 (E4M3/E5M2) quantization for model weights. Unlike
+/// `quantize_model_weights`'s int8 scaling, this is a true logarithmic
+/// quantizer, so it preserves dynamic range across weights spanning
+/// several orders of magnitude instead of flattening small values toward
+/// zero. `format` is `"e4m3"` (max magnitude ~448) or `"e5m2"` (max
+/// magnitude ~57344). Returns `{quantized, format}`; reconstruct with
+/// `dequantize_fp8`.
+#[pyfunction]
+#[pyo3(signature = (weights, format="e4m3"))]
+fn quantize_model_weights_fp8(
+    py: Python<'_>,
+    weights: PyReadonlyArrayDyn<f32>,
+    format: &str,
+) -> PyResult<HashMap<String, PyObject>> {
+    let fmt = fp8_format(format)?;
+    let values = weights.as_array();
+    let quantized: Vec<u8> = values.iter().map(|value| encode_fp8(*value, fmt)).collect();
+
+    let mut result = HashMap::new();
+    result.insert("quantized".to_string(), quantized.to_object(py));
+    result.insert("format".to_string(), fmt.name.to_object(py));
+    Ok(result)
+}
+
+/// Reconstruct `f32` weights from FP8 codes produced by
+/// `quantize_model_weights_fp8`.
+#[pyfunction]
+fn dequantize_fp8(codes: Vec<u8>, format: &str) -> PyResult<Vec<f32>> {
+    let fmt = fp8_format(format)?;
+    Ok(codes.iter().map(|code| decode_fp8(*code, fmt)).collect())
+}
+
+/// Minimal, dependency-free PRNG (xorshift64) seeded from the sampled
+/// pixels so k-means++ initialization is deterministic and reproducible
+/// without pulling in the `rand` crate for this one call site.
+struct XorShiftRng(u64);
+
+impl XorShiftRng {
+    fn new(seed: u64) -> Self {
+        Self(if seed == 0 { 0x9E3779B97F4A7C15 } else { seed })
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Hash the sampled colors into a PRNG seed so the same image always
+/// clusters the same way.
+fn seed_from_colors(colors: &[(u8, u8, u8)]) -> u64 {
+    let mut seed = 0xcbf29ce484222325u64; // FNV-1a offset basis
+    for &(r, g, b) in colors {
+        for byte in [r, g, b] {
+            seed ^= byte as u64;
+            seed = seed.wrapping_mul(0x100000001b3);
+        }
+    }
+    seed
+}
+
+fn srgb_channel_to_linear(c: f64) -> f64 {
+    let c = c / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb_channel(c: f64) -> f64 {
+    let c = c.clamp(0.0, 1.0);
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// sRGB (D65) -> CIE L*a*b*.
+fn rgb_to_lab(r: u8, g: u8, b: u8) -> [f64; 3] {
+    let r = srgb_channel_to_linear(r as f64);
+    let g = srgb_channel_to_linear(g as f64);
+    let b = srgb_channel_to_linear(b as f64);
+
+    let x = (r * 0.4124564 + g * 0.3575761 + b * 0.1804375) / 0.95047;
+    let y = r * 0.2126729 + g * 0.7151522 + b * 0.0721750;
+    let z = (r * 0.0193339 + g * 0.1191920 + b * 0.9503041) / 1.08883;
+
+    fn f(t: f64) -> f64 {
+        if t > 0.008856 {
+            t.cbrt()
+        } else {
+            7.787 * t + 16.0 / 116.0
+        }
+    }
+
+    let (fx, fy, fz) = (f(x), f(y), f(z));
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+/// CIE L*a*b* -> sRGB (D65), the inverse of [`rgb_to_lab`].
+fn lab_to_rgb(lab: [f64; 3]) -> (u8, u8, u8) {
+    let [l, a, b] = lab;
+    let fy = (l + 16.0) / 116.0;
+    let fx = fy + a / 500.0;
+    let fz = fy - b / 200.0;
+
+    fn finv(t: f64) -> f64 {
+        if t.powi(3) > 0.008856 {
+            t.powi(3)
+        } else {
+            (t - 16.0 / 116.0) / 7.787
+        }
+    }
+
+    let x = finv(fx) * 0.95047;
+    let y = finv(fy);
+    let z = finv(fz) * 1.08883;
+
+    let r = x * 3.2404542 + y * -1.5371385 + z * -0.4985314;
+    let g = x * -0.9692660 + y * 1.8760108 + z * 0.0415560;
+    let b = x * 0.0556434 + y * -0.2040259 + z * 1.0572252;
+
+    (
+        (linear_to_srgb_channel(r) * 255.0).round().clamp(0.0, 255.0) as u8,
+        (linear_to_srgb_channel(g) * 255.0).round().clamp(0.0, 255.0) as u8,
+        (linear_to_srgb_channel(b) * 255.0).round().clamp(0.0, 255.0) as u8,
+    )
+}
+
+fn dist_sq(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    let (dx, dy, dz) = (a[0] - b[0], a[1] - b[1], a[2] - b[2]);
+    dx * dx + dy * dy + dz * dz
+}
+
+/// k-means++ seeding: pick the first centroid uniformly, then each
+/// subsequent centroid with probability proportional to its squared
+/// distance from the nearest centroid already chosen, so initial
+/// centroids start spread across the color space instead of clumped.
+fn kmeans_plus_plus_init(points: &[[f64; 3]], k: usize, rng: &mut XorShiftRng) -> Vec<[f64; 3]> {
+    let mut centroids = Vec::with_capacity(k);
+    let first = ((rng.next_f64() * points.len() as f64) as usize).min(points.len() - 1);
+    centroids.push(points[first]);
+
+    while centroids.len() < k {
+        let distances: Vec<f64> = points
+            .iter()
+            .map(|p| {
+                centroids
+                    .iter()
+                    .map(|c| dist_sq(p, c))
+                    .fold(f64::INFINITY, f64::min)
+            })
+            .collect();
+        let total: f64 = distances.iter().sum();
+
+        if total <= 0.0 {
+            centroids.push(points[centroids.len() % points.len()]);
+            continue;
+        }
+
+        let target = rng.next_f64() * total;
+        let mut acc = 0.0;
+        let mut chosen = points.len() - 1;
+        for (i, d) in distances.iter().enumerate() {
+            acc += *d;
+            if acc >= target {
+                chosen = i;
+                break;
+            }
+        }
+        centroids.push(points[chosen]);
+    }
+
+    centroids
+}
+
+/// Bound on Lloyd's-algorithm iterations; in practice assignments
+/// stabilize well before this on natural images, but it guarantees
+/// termination regardless.
+const KMEANS_MAX_ITERATIONS: usize = 20;
+
+/// Lloyd's k-means: assign each point to its nearest centroid, recompute
+/// centroids as the mean of their assigned points, and repeat until no
+/// assignment changes or `KMEANS_MAX_ITERATIONS` is hit.
+fn lloyds_kmeans(points: &[[f64; 3]], k: usize, seed: u64) -> (Vec<[f64; 3]>, Vec<usize>) {
+    let mut rng = XorShiftRng::new(seed);
+    let mut centroids = kmeans_plus_plus_init(points, k, &mut rng);
+    let mut assignments = vec![0usize; points.len()];
+
+    for _ in 0..KMEANS_MAX_ITERATIONS {
+        let mut changed = false;
+        for (i, point) in points.iter().enumerate() {
+            let mut best = 0;
+            let mut best_dist = f64::INFINITY;
+            for (c_idx, centroid) in centroids.iter().enumerate() {
+                let d = dist_sq(point, centroid);
+                if d < best_dist {
+                    best_dist = d;
+                    best = c_idx;
+                }
+            }
+            if assignments[i] != best {
+                assignments[i] = best;
+                changed = true;
+            }
+        }
+
+        let mut sums = vec![[0.0f64; 3]; k];
+        let mut counts = vec![0usize; k];
+        for (point, &cluster) in points.iter().zip(assignments.iter()) {
+            sums[cluster][0] += point[0];
+            sums[cluster][1] += point[1];
+            sums[cluster][2] += point[2];
+            counts[cluster] += 1;
+        }
+        for (c_idx, count) in counts.iter().enumerate() {
+            if *count > 0 {
+                centroids[c_idx] = [
+                    sums[c_idx][0] / *count as f64,
+                    sums[c_idx][1] / *count as f64,
+                    sums[c_idx][2] / *count as f64,
+                ];
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    (centroids, assignments)
+}
+
+/// Extract the image's dominant colors via k-means++ clustering (in RGB
+/// or, with `color_space="lab"`, perceptual CIELAB space) instead of
+/// tallying exact `(u8,u8,u8)` tuples, which on photographic/gradient
+/// content explodes into thousands of near-duplicate singleton shades
+/// and misses the colors a viewer would call dominant.
+///
+/// Returns one `(r, g, b, population_fraction)` tuple per cluster,
+/// sorted by population descending, so callers can see how dominant
+/// each color actually is rather than just a ranked list.
 #[pyfunction]
-#[pyo3(signature = (image, num_colors=5))]
+#[pyo3(signature = (image, num_colors=5, color_space="rgb"))]
 fn extract_dominant_colors(
     image: PyReadonlyArrayDyn<u8>,
     num_colors: usize,
-) -> PyResult<Vec<(u8, u8, u8)>> {
+    color_space: &str,
+) -> PyResult<Vec<(u8, u8, u8, f64)>> {
+    if color_space != "rgb" && color_space != "lab" {
+        return Err(PyValueError::new_err(format!(
+            "unknown color_space: {} (expected \"rgb\" or \"lab\")",
+            color_space
+        )));
+    }
+
     let data = numpy_to_image(image)?;
     let pixels = data.as_slice();
     if pixels.is_empty() || num_colors == 0 {
@@ -814,7 +2641,7 @@ fn extract_dominant_colors(
     let channels = data.channels as usize;
     let pixel_count = (data.width as usize) * (data.height as usize);
     let sample_stride = (pixel_count / 200_000).max(1);
-    let mut frequencies: HashMap<(u8, u8, u8), usize> = HashMap::new();
+    let mut sampled: Vec<(u8, u8, u8)> = Vec::new();
 
     for pixel_index in (0..pixel_count).step_by(sample_stride) {
         let offset = pixel_index * channels;
@@ -823,25 +2650,63 @@ fn extract_dominant_colors(
         }
 
         let color = match channels {
-            1 => {
-                let v = pixels[offset];
-                (v, v, v)
-            }
-            2 => {
+            1 | 2 => {
                 let v = pixels[offset];
                 (v, v, v)
             }
             3 | 4 => (pixels[offset], pixels[offset + 1], pixels[offset + 2]),
             _ => continue,
         };
-        *frequencies.entry(color).or_insert(0) += 1;
+        sampled.push(color);
+    }
+
+    if sampled.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // Fewer samples than requested clusters: cap k at the sample count so
+    // k-means is never asked for more clusters than it has points.
+    let k = num_colors.min(sampled.len());
+
+    let points: Vec<[f64; 3]> = sampled
+        .iter()
+        .map(|&(r, g, b)| {
+            if color_space == "lab" {
+                rgb_to_lab(r, g, b)
+            } else {
+                [r as f64, g as f64, b as f64]
+            }
+        })
+        .collect();
+
+    let seed = seed_from_colors(&sampled);
+    let (centroids, assignments) = lloyds_kmeans(&points, k, seed);
+
+    let mut counts = vec![0usize; k];
+    for &cluster in &assignments {
+        counts[cluster] += 1;
     }
 
-    let mut ranked: Vec<((u8, u8, u8), usize)> = frequencies.into_iter().collect();
-    ranked.sort_by(|a, b| b.1.cmp(&a.1));
-    ranked.truncate(num_colors);
+    let total = sampled.len() as f64;
+    let mut clusters: Vec<(u8, u8, u8, f64)> = centroids
+        .into_iter()
+        .enumerate()
+        .map(|(idx, centroid)| {
+            let (r, g, b) = if color_space == "lab" {
+                lab_to_rgb(centroid)
+            } else {
+                (
+                    centroid[0].round().clamp(0.0, 255.0) as u8,
+                    centroid[1].round().clamp(0.0, 255.0) as u8,
+                    centroid[2].round().clamp(0.0, 255.0) as u8,
+                )
+            };
+            (r, g, b, counts[idx] as f64 / total)
+        })
+        .collect();
 
-    Ok(ranked.into_iter().map(|(color, _)| color).collect())
+    clusters.sort_by(|a, b| b.3.partial_cmp(&a.3).unwrap());
+    Ok(clusters)
 }
 
 #[pyfunction]
@@ -959,6 +2824,10 @@ pub fn register_python_module(m: &PyModule) -> PyResult<()> {
     m.add_class::<PyRustRuntimeManager>()?;
     m.add_class::<PyRustAdvancedMemoryPool>()?;
     m.add_class::<PyRustTrackedBuffer>()?;
+    m.add_class::<PyFrameView>()?;
+    m.add_class::<PyFrameStream>()?;
+    m.add_class::<PySubscription>()?;
+    m.add_class::<PyGpuAccelerator>()?;
 
     // Compatibility alias used by some Python call sites.
     let memory_cls = m.getattr("MemoryManager")?;
@@ -989,6 +2858,9 @@ pub fn register_python_module(m: &PyModule) -> PyResult<()> {
     // Register free functions consumed by Python wrappers.
     m.add_function(wrap_pyfunction!(process_image_batch, m)?)?;
     m.add_function(wrap_pyfunction!(quantize_model_weights, m)?)?;
+    m.add_function(wrap_pyfunction!(quantize_model_weights_ex, m)?)?;
+    m.add_function(wrap_pyfunction!(quantize_model_weights_fp8, m)?)?;
+    m.add_function(wrap_pyfunction!(dequantize_fp8, m)?)?;
     m.add_function(wrap_pyfunction!(extract_dominant_colors, m)?)?;
     m.add_function(wrap_pyfunction!(calculate_edge_density, m)?)?;
     m.add_function(wrap_pyfunction!(analyze_texture, m)?)?;
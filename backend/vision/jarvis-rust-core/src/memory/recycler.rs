@@ -1,19 +1,51 @@
 //! Buffer recycling system for zero-allocation operations
 
 use std::sync::{Arc, Weak};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
 use parking_lot::Mutex;
 use std::collections::VecDeque;
 use crate::Result;
 
-/// Buffer recycler for reusing allocations
+/// Window (in acquisitions) over which each bin's EMA occupancy is tracked.
+/// Chosen so the half-life spans thousands of allocations, giving a cushion
+/// against normal variation while still bounding memory after a burst.
+const WINDOW: usize = 16_384;
+
+/// Floor below which the shrink policy never reclaims buffers, so bins
+/// stay warm for light, bursty workloads instead of thrashing.
+const SHRINK_SIZE: usize = 10;
+
+/// Buffer recycler for reusing allocations. A thin, size-classed
+/// specialization of [`Pool<Vec<u8>, WithCapacity>`]: one `Pool` per power-
+/// of-two size class handles the actual "reuse a returned buffer or
+/// allocate a fresh one" bookkeeping (and its stats), while this type adds
+/// the policy on top — routing an acquisition to the right size class, an
+/// EMA-based shrink pass run before each acquire, and rejecting returns
+/// that outgrew their class instead of recycling them.
 pub struct BufferRecycler {
-    bins: Vec<Mutex<RecycleBin>>,
+    size_classes: Vec<usize>,
+    bins: Vec<Arc<Pool<Vec<u8>, WithCapacity>>>,
+    /// `WINDOW` times the EMA of each bin's retained-buffer count; the
+    /// ideal retained count for bin `i` is `size_factors[i] / WINDOW`.
+    /// Updated on every `acquire` for that bin.
+    size_factors: Vec<Mutex<usize>>,
+    freed: AtomicUsize,
+    max_gc: AtomicUsize,
 }
 
-struct RecycleBin {
-    size_class: usize,
-    buffers: VecDeque<Vec<u8>>,
-    max_buffers: usize,
+/// Point-in-time snapshot of recycler effectiveness, returned by `stats()`.
+///
+/// `reuse_count` vs `total_allocated` gives the hit rate; `max_gc` is the
+/// high-water mark of buffers retained in any single bin, useful for
+/// judging how aggressively the EMA shrink policy is reclaiming memory.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RecyclerStats {
+    pub total_allocated: usize,
+    pub reuse_count: usize,
+    pub freed: usize,
+    pub max_gc: usize,
 }
 
 impl BufferRecycler {
@@ -23,15 +55,56 @@ impl BufferRecycler {
             256, 512, 1024, 2048, 4096, 8192, 16384, 32768, 65536, 131072
         ];
 
-        let bins = size_classes.into_iter()
-            .map(|size| Mutex::new(RecycleBin {
-                size_class: size,
-                buffers: VecDeque::new(),
-                max_buffers: 10,
-            }))
-            .collect();
+        // Returns always accept (see `recycle`); the EMA shrink pass run on
+        // `acquire` is what bounds each bin, so the underlying pool itself
+        // is left unbounded here.
+        let bins = size_classes.iter().map(|_| Pool::new(usize::MAX)).collect();
+        let size_factors = size_classes.iter().map(|_| Mutex::new(0)).collect();
 
-        Self { bins }
+        Self {
+            size_classes,
+            bins,
+            size_factors,
+            freed: AtomicUsize::new(0),
+            max_gc: AtomicUsize::new(0),
+        }
+    }
+
+    /// Snapshot of allocation/reuse counters since creation.
+    pub fn stats(&self) -> RecyclerStats {
+        let mut total_allocated = 0;
+        let mut reuse_count = 0;
+        for bin in &self.bins {
+            let pool_stats = bin.stats();
+            total_allocated += pool_stats.total_allocated;
+            reuse_count += pool_stats.reuse_count;
+        }
+
+        RecyclerStats {
+            total_allocated,
+            reuse_count,
+            freed: self.freed.load(Ordering::Relaxed),
+            max_gc: self.max_gc.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Record a new high-water mark for buffers retained in a single bin.
+    fn record_max_gc(&self, len: usize) {
+        self.max_gc.fetch_max(len, Ordering::Relaxed);
+    }
+
+    /// Update a bin's EMA occupancy estimate and shrink it back to the
+    /// ideal retained count, but never below `SHRINK_SIZE`, if it has
+    /// grown past a transient burst.
+    fn shrink_bin(&self, bin_idx: usize) {
+        let mut factor = self.size_factors[bin_idx].lock();
+        let current_len = self.bins[bin_idx].len();
+        *factor = *factor - *factor / WINDOW + current_len;
+        let ideal = *factor / WINDOW;
+
+        if current_len > SHRINK_SIZE && current_len > ideal {
+            self.bins[bin_idx].shrink_to(ideal.max(SHRINK_SIZE));
+        }
     }
 
     /// Get recycled buffer or allocate new one.
@@ -39,65 +112,82 @@ impl BufferRecycler {
     /// back to this recycler, avoiding use-after-free.
     pub fn acquire(self: &Arc<Self>, size: usize) -> RecyclableBuffer {
         let weak = Arc::downgrade(self);
-
-        // Find appropriate bin
         let bin_idx = self.find_bin_index(size);
 
-        if let Some(bin_mutex) = self.bins.get(bin_idx) {
-            let mut bin = bin_mutex.lock();
-
-            // Try to get recycled buffer
-            if let Some(mut buffer) = bin.buffers.pop_front() {
-                buffer.clear();
-                buffer.resize(size, 0);
-                return RecyclableBuffer {
-                    buffer,
-                    recycler: Some(weak),
-                    original_capacity: bin.size_class,
-                };
-            }
-        }
+        self.shrink_bin(bin_idx);
+
+        let mut recycled = self.bins[bin_idx].acquire();
+        recycled.resize(size, 0);
 
-        // No recycled buffer available, allocate new
-        let capacity = self.round_up_size(size);
         RecyclableBuffer {
-            buffer: vec![0u8; size],
+            buffer: recycled.into_inner(),
             recycler: Some(weak),
-            original_capacity: capacity,
+            original_capacity: self.size_classes[bin_idx],
+            in_use_limit: None,
         }
     }
 
-    /// Return buffer for recycling
-    fn recycle(&self, mut buffer: Vec<u8>, original_capacity: usize) {
-        let bin_idx = self.find_bin_index(original_capacity);
+    /// Like `acquire`, but rejects the request with `None` once `limit`
+    /// already has as many outstanding (not-yet-dropped) buffers as its
+    /// configured bound. Allocation limits belong at the call site —
+    /// callers that need backpressure opt into this; everyone else keeps
+    /// the unbounded `acquire`.
+    pub fn try_acquire(self: &Arc<Self>, size: usize, limit: &Arc<InUseLimit>) -> Option<RecyclableBuffer> {
+        let previous = limit.in_use.fetch_add(1, Ordering::AcqRel);
+        if previous >= limit.limit {
+            limit.in_use.fetch_sub(1, Ordering::AcqRel);
+            return None;
+        }
 
-        if let Some(bin_mutex) = self.bins.get(bin_idx) {
-            let mut bin = bin_mutex.lock();
+        let mut buffer = self.acquire(size);
+        buffer.in_use_limit = Some(limit.clone());
+        Some(buffer)
+    }
 
-            // Only keep if under limit and buffer is reasonably sized
-            if bin.buffers.len() < bin.max_buffers && buffer.capacity() <= bin.size_class * 2 {
-                buffer.clear();
-                bin.buffers.push_back(buffer);
-            }
+    /// Return buffer for recycling. Always accepted into its bin's pool —
+    /// bounded only by the EMA shrink policy on the next `acquire`, not by
+    /// a hard cap here — unless it outgrew its size class, in which case
+    /// it's dropped instead of recycled.
+    fn recycle(&self, buffer: Vec<u8>, original_capacity: usize) {
+        let bin_idx = self.find_bin_index(original_capacity);
+
+        if buffer.capacity() <= self.size_classes[bin_idx] * 2 {
+            self.bins[bin_idx].recycle(buffer);
+            self.record_max_gc(self.bins[bin_idx].len());
+        } else {
+            self.freed.fetch_add(1, Ordering::Relaxed);
         }
     }
 
     /// Find bin index for size
     fn find_bin_index(&self, size: usize) -> usize {
-        self.bins.iter()
-            .position(|bin| bin.lock().size_class >= size)
-            .unwrap_or(self.bins.len() - 1)
+        self.size_classes.iter()
+            .position(|&size_class| size_class >= size)
+            .unwrap_or(self.size_classes.len() - 1)
     }
+}
 
-    /// Round up to next size class
-    fn round_up_size(&self, size: usize) -> usize {
-        for bin in &self.bins {
-            let size_class = bin.lock().size_class;
-            if size_class >= size {
-                return size_class;
-            }
-        }
-        size
+/// Rate-limits outstanding (acquired-but-not-yet-dropped) buffers via an
+/// atomic counter, for use with [`BufferRecycler::try_acquire`]. This is
+/// the allocation cap the old `max_buffers` field used to conflate with
+/// recycling: a call-site concern (how many buffers may exist at once),
+/// kept separate from the pool's own retention policy.
+pub struct InUseLimit {
+    limit: usize,
+    in_use: AtomicUsize,
+}
+
+impl InUseLimit {
+    pub fn new(limit: usize) -> Arc<Self> {
+        Arc::new(Self {
+            limit,
+            in_use: AtomicUsize::new(0),
+        })
+    }
+
+    /// Current number of outstanding buffers acquired against this limit.
+    pub fn in_use(&self) -> usize {
+        self.in_use.load(Ordering::Relaxed)
     }
 }
 
@@ -108,6 +198,7 @@ pub struct RecyclableBuffer {
     buffer: Vec<u8>,
     recycler: Option<Weak<BufferRecycler>>,
     original_capacity: usize,
+    in_use_limit: Option<Arc<InUseLimit>>,
 }
 
 impl RecyclableBuffer {
@@ -117,6 +208,7 @@ impl RecyclableBuffer {
             buffer: vec![0u8; size],
             recycler: None,
             original_capacity: size,
+            in_use_limit: None,
         }
     }
 
@@ -145,10 +237,55 @@ impl RecyclableBuffer {
         self.recycler = None;  // Prevent recycling
         std::mem::take(&mut self.buffer)
     }
+
+    /// Repurpose this buffer's byte capacity as a fixed-length scratch view
+    /// of a `Copy` (POD) element type `T`, reusing the same allocation
+    /// across types instead of needing a second pool for typed scratch in
+    /// numeric/serialization hot paths — the idea behind the experimental
+    /// `Vec::recycle`.
+    ///
+    /// Requires `self` to be empty (`len() == 0`) and its byte capacity to
+    /// be a multiple of `size_of::<T>()` with matching alignment; returns
+    /// `None` otherwise. Only element types with no drop glue are
+    /// permitted (`Copy` enforces this). Unlike a `Vec<T>`, the view can't
+    /// grow past `byte_capacity / size_of::<T>()` elements — the backing
+    /// allocation stays a `Vec<u8>` for its entire life (never reallocated
+    /// or freed under `T`'s layout) and the guard only ever hands out
+    /// `&[T]`/`&mut [T]` over its existing capacity, so reusing it across
+    /// element types can't produce an allocator layout mismatch. The guard
+    /// returns the storage to this buffer's size class on drop.
+    pub fn recycle_into<T: Copy>(mut self) -> Option<TypedRecycleGuard<T>> {
+        if !self.buffer.is_empty() {
+            return None;
+        }
+
+        let elem_size = std::mem::size_of::<T>();
+        let byte_capacity = self.buffer.capacity();
+        if elem_size == 0 || byte_capacity % elem_size != 0 {
+            return None;
+        }
+        if (self.buffer.as_ptr() as usize) % std::mem::align_of::<T>() != 0 {
+            return None;
+        }
+
+        let recycler = self.recycler.take();
+        let buffer = std::mem::take(&mut self.buffer);
+        let elem_count = buffer.capacity() / elem_size;
+
+        Some(TypedRecycleGuard {
+            buffer: Some(buffer),
+            elem_count,
+            recycler,
+            _marker: PhantomData,
+        })
+    }
 }
 
 impl Drop for RecyclableBuffer {
     fn drop(&mut self) {
+        if let Some(limit) = self.in_use_limit.take() {
+            limit.in_use.fetch_sub(1, Ordering::AcqRel);
+        }
         if let Some(weak) = &self.recycler {
             if !self.buffer.is_empty() {
                 if let Some(recycler) = weak.upgrade() {
@@ -166,6 +303,248 @@ impl Drop for RecyclableBuffer {
 // Weak<BufferRecycler> is Send+Sync when BufferRecycler is Send+Sync,
 // and Vec<u8> is Send+Sync. No raw pointers remain.
 
+/// A `RecyclableBuffer`'s byte capacity, exposed as a fixed-length
+/// `&[T]`/`&mut [T]` scratch view. See [`RecyclableBuffer::recycle_into`].
+///
+/// The backing allocation is kept as a `Vec<u8>` for the guard's entire
+/// lifetime — it's never reinterpreted as a `Vec<T>`. Converting it to
+/// `Vec<T>` (as an earlier version of this guard did) is unsound: a
+/// `Vec<u8>` allocation has alignment 1, but `Vec<T>` would realloc and
+/// dealloc it using `align_of::<T>()`, a layout mismatch the allocator
+/// contract doesn't allow even though read/write access is fine. Handing
+/// out slice views over the untouched `Vec<u8>` sidesteps that: there's no
+/// realloc to mismatch, and the final dealloc always happens through the
+/// `Vec<u8>` it actually is.
+pub struct TypedRecycleGuard<T: Copy> {
+    buffer: Option<Vec<u8>>,
+    elem_count: usize,
+    recycler: Option<Weak<BufferRecycler>>,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Copy> Deref for TypedRecycleGuard<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        let buffer = self.buffer.as_ref().expect("buffer taken before drop");
+        // Safety: `recycle_into` checked the buffer's capacity is an exact,
+        // correctly-aligned multiple of `size_of::<T>()` holding
+        // `elem_count` elements; the buffer is never resized or moved
+        // while this guard lives, so the pointer and length stay valid for
+        // `T`'s duration here. `T: Copy` rules out any drop glue this view
+        // could leak.
+        unsafe { std::slice::from_raw_parts(buffer.as_ptr() as *const T, self.elem_count) }
+    }
+}
+
+impl<T: Copy> DerefMut for TypedRecycleGuard<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        let elem_count = self.elem_count;
+        let buffer = self.buffer.as_mut().expect("buffer taken before drop");
+        // Safety: see `deref`; `&mut` access is exclusive through `self`.
+        unsafe { std::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut T, elem_count) }
+    }
+}
+
+impl<T: Copy> Drop for TypedRecycleGuard<T> {
+    fn drop(&mut self) {
+        let Some(buffer) = self.buffer.take() else {
+            return;
+        };
+        let byte_capacity = buffer.capacity();
+
+        match self.recycler.as_ref().and_then(Weak::upgrade) {
+            Some(recycler) => recycler.recycle(buffer, byte_capacity),
+            None => {
+                // Recycler gone (or buffer had none) — `buffer` (a proper
+                // `Vec<u8>`) deallocates normally when dropped here.
+            }
+        }
+    }
+}
+
+// ============================================================================
+// GENERIC OBJECT POOL
+// ============================================================================
+
+/// A pluggable reset policy for pooled elements of type `T`.
+///
+/// `Pool<T, R>` generalizes the "allocate fresh or reuse a returned
+/// element" half of [`BufferRecycler`]'s idea to any `T` whose callers can
+/// define how to construct and reset one — `String`, `Vec<T>`, or domain
+/// structs that own file descriptors, connections, or scratch allocations.
+/// `BufferRecycler` itself is a thin specialization of `Pool<Vec<u8>,
+/// WithCapacity>`: one `Pool` per size class, with size-class routing and
+/// an EMA shrink policy layered on top.
+pub trait Recycle<T> {
+    /// Construct a fresh element when the pool has nothing to reuse.
+    fn new_element() -> T;
+    /// Prepare a previously-used element for its next owner.
+    fn recycle(element: &mut T);
+}
+
+/// Reset policy for standard collections: clears contents in place,
+/// retaining the underlying heap allocation for reuse.
+pub struct WithCapacity;
+
+impl Recycle<String> for WithCapacity {
+    fn new_element() -> String {
+        String::new()
+    }
+
+    fn recycle(element: &mut String) {
+        element.clear();
+    }
+}
+
+impl<T> Recycle<Vec<T>> for WithCapacity {
+    fn new_element() -> Vec<T> {
+        Vec::new()
+    }
+
+    fn recycle(element: &mut Vec<T>) {
+        element.clear();
+    }
+}
+
+/// Implemented by types that know how to restore themselves to a
+/// reusable state, for [`DefaultReset`].
+pub trait Resettable {
+    fn reset(&mut self);
+}
+
+/// Reset policy that defers to a caller-supplied [`Resettable::reset`],
+/// for domain structs that need more than "clear the container".
+pub struct DefaultReset;
+
+impl<T: Default + Resettable> Recycle<T> for DefaultReset {
+    fn new_element() -> T {
+        T::default()
+    }
+
+    fn recycle(element: &mut T) {
+        element.reset();
+    }
+}
+
+/// Point-in-time snapshot of a [`Pool`]'s allocation/reuse counters.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PoolStats {
+    pub total_allocated: usize,
+    pub reuse_count: usize,
+}
+
+/// A generic, single-bin object pool built on a [`Recycle`] policy.
+pub struct Pool<T, R: Recycle<T>> {
+    elements: Mutex<VecDeque<T>>,
+    max_elements: usize,
+    total_allocated: AtomicUsize,
+    reuse_count: AtomicUsize,
+    _policy: PhantomData<fn() -> R>,
+}
+
+impl<T, R: Recycle<T>> Pool<T, R> {
+    pub fn new(max_elements: usize) -> Arc<Self> {
+        Arc::new(Self {
+            elements: Mutex::new(VecDeque::new()),
+            max_elements,
+            total_allocated: AtomicUsize::new(0),
+            reuse_count: AtomicUsize::new(0),
+            _policy: PhantomData,
+        })
+    }
+
+    /// Get a recycled element or construct a fresh one via `R::new_element`.
+    pub fn acquire(self: &Arc<Self>) -> Recycled<T, R> {
+        let element = match self.elements.lock().pop_front() {
+            Some(element) => {
+                self.reuse_count.fetch_add(1, Ordering::Relaxed);
+                element
+            }
+            None => {
+                self.total_allocated.fetch_add(1, Ordering::Relaxed);
+                R::new_element()
+            }
+        };
+
+        Recycled {
+            element: Some(element),
+            pool: Some(Arc::downgrade(self)),
+        }
+    }
+
+    fn recycle(&self, mut element: T) {
+        R::recycle(&mut element);
+
+        let mut elements = self.elements.lock();
+        if elements.len() < self.max_elements {
+            elements.push_back(element);
+        }
+    }
+
+    /// Number of elements currently retained for reuse.
+    pub fn len(&self) -> usize {
+        self.elements.lock().len()
+    }
+
+    /// Drop retained elements back to `target`, discarding the rest. Used
+    /// by [`BufferRecycler`]'s EMA shrink policy to reclaim a bin that has
+    /// grown past a transient burst.
+    pub fn shrink_to(&self, target: usize) {
+        let mut elements = self.elements.lock();
+        while elements.len() > target {
+            elements.pop_back();
+        }
+    }
+
+    /// Snapshot of this pool's allocation/reuse counters since creation.
+    pub fn stats(&self) -> PoolStats {
+        PoolStats {
+            total_allocated: self.total_allocated.load(Ordering::Relaxed),
+            reuse_count: self.reuse_count.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Element on loan from a [`Pool`]; returned for recycling on drop.
+pub struct Recycled<T, R: Recycle<T>> {
+    element: Option<T>,
+    pool: Option<Weak<Pool<T, R>>>,
+}
+
+impl<T, R: Recycle<T>> Recycled<T, R> {
+    /// Detach the element from this guard without returning it to the
+    /// pool, e.g. to hand it to a different recycling scheme. The guard's
+    /// `Drop` becomes a no-op once this has been called.
+    pub fn into_inner(mut self) -> T {
+        self.element.take().expect("element taken before drop")
+    }
+}
+
+impl<T, R: Recycle<T>> Deref for Recycled<T, R> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.element.as_ref().expect("element taken before drop")
+    }
+}
+
+impl<T, R: Recycle<T>> DerefMut for Recycled<T, R> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.element.as_mut().expect("element taken before drop")
+    }
+}
+
+impl<T, R: Recycle<T>> Drop for Recycled<T, R> {
+    fn drop(&mut self) {
+        if let Some(element) = self.element.take() {
+            if let Some(pool) = self.pool.as_ref().and_then(Weak::upgrade) {
+                pool.recycle(element);
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -204,6 +583,46 @@ mod tests {
         drop(buf); // No recycler, drops cleanly
     }
 
+    #[test]
+    fn test_stats_track_allocation_and_reuse() {
+        let recycler = Arc::new(BufferRecycler::new());
+
+        {
+            let _buf = recycler.acquire(500);
+        }
+        let stats = recycler.stats();
+        assert_eq!(stats.total_allocated, 1);
+        assert_eq!(stats.reuse_count, 0);
+
+        let _buf = recycler.acquire(500);
+        let stats = recycler.stats();
+        assert_eq!(stats.reuse_count, 1);
+        assert_eq!(stats.max_gc, 1);
+    }
+
+    #[test]
+    fn test_ema_shrink_releases_buffers_after_burst() {
+        // A bin holding well above SHRINK_SIZE buffers, whose EMA has
+        // already settled on a low steady-state occupancy (as if the
+        // burst that filled it was transient), should shrink on the next
+        // shrink pass: down to SHRINK_SIZE, the floor it never goes below,
+        // since the ideal count of 2 is lower still.
+        let recycler = Arc::new(BufferRecycler::new());
+        let bin_idx = 0;
+
+        {
+            let mut elements = recycler.bins[bin_idx].elements.lock();
+            for _ in 0..SHRINK_SIZE + 5 {
+                elements.push_back(Vec::new());
+            }
+        }
+        *recycler.size_factors[bin_idx].lock() = 2 * WINDOW; // ideal == 2
+
+        recycler.shrink_bin(bin_idx);
+
+        assert_eq!(recycler.bins[bin_idx].len(), SHRINK_SIZE);
+    }
+
     #[test]
     fn test_into_vec_prevents_recycling() {
         let recycler = Arc::new(BufferRecycler::new());
@@ -212,4 +631,118 @@ mod tests {
         assert_eq!(vec.len(), 100);
         // Buffer consumed, no Drop/recycle happens
     }
+
+    #[test]
+    fn test_try_acquire_rejects_beyond_in_use_limit() {
+        let recycler = Arc::new(BufferRecycler::new());
+        let limit = InUseLimit::new(2);
+
+        let buf1 = recycler.try_acquire(100, &limit).expect("under limit");
+        let buf2 = recycler.try_acquire(100, &limit).expect("at limit");
+        assert_eq!(limit.in_use(), 2);
+        assert!(recycler.try_acquire(100, &limit).is_none());
+
+        drop(buf1);
+        assert_eq!(limit.in_use(), 1);
+        let buf3 = recycler.try_acquire(100, &limit).expect("freed a slot");
+        drop(buf2);
+        drop(buf3);
+    }
+
+    #[test]
+    fn test_recycle_always_accepts_returns_beyond_old_hard_cap() {
+        let recycler = Arc::new(BufferRecycler::new());
+
+        // Previously capped at 10 per bin; now bounded only by the shrink
+        // policy, so returning more than that should not drop buffers.
+        let mut held = Vec::new();
+        for _ in 0..25 {
+            held.push(recycler.acquire(256));
+        }
+        drop(held);
+
+        assert_eq!(recycler.bins[0].len(), 25);
+    }
+
+    #[test]
+    fn test_recycle_into_reuses_capacity_for_pod_type() {
+        let recycler = Arc::new(BufferRecycler::new());
+        let mut buf = recycler.acquire(1024);
+        buf.resize(0, 0); // must be empty to repurpose
+
+        let mut scratch = buf.recycle_into::<u32>().expect("capacity is u32-aligned");
+        scratch[0..3].copy_from_slice(&[1, 2, 3]);
+        assert_eq!(&scratch[0..3], &[1, 2, 3]);
+        drop(scratch); // returned to its size class as the Vec<u8> it always was
+
+        let stats = recycler.stats();
+        assert_eq!(stats.total_allocated, 1);
+    }
+
+    #[test]
+    fn test_recycle_into_view_is_fixed_to_original_capacity() {
+        let recycler = Arc::new(BufferRecycler::new());
+        let mut buf = recycler.acquire(16); // 4 u32s worth of capacity
+        buf.resize(0, 0);
+
+        // Unlike a `Vec<u8>`-reinterpreted-as-`Vec<T>`, the scratch view
+        // can't grow past the capacity it was carved from — there's no
+        // `push`/`extend` to reallocate it under a mismatched alignment.
+        let mut scratch = buf.recycle_into::<u32>().expect("capacity is u32-aligned");
+        assert_eq!(scratch.len(), 4);
+        scratch.copy_from_slice(&[1, 2, 3, 4]);
+        assert_eq!(&scratch[..], &[1, 2, 3, 4]);
+        drop(scratch);
+
+        let stats = recycler.stats();
+        assert_eq!(stats.total_allocated, 1);
+    }
+
+    #[test]
+    fn test_recycle_into_rejects_non_empty_buffer() {
+        let buf = RecyclableBuffer::new(16);
+        assert!(buf.recycle_into::<u32>().is_none());
+    }
+
+    #[test]
+    fn test_generic_pool_reuses_string_capacity() {
+        let pool: Arc<Pool<String, WithCapacity>> = Pool::new(4);
+
+        {
+            let mut s = pool.acquire();
+            s.push_str("hello");
+        } // returned and cleared, capacity retained
+
+        let s = pool.acquire();
+        assert_eq!(s.as_str(), "");
+    }
+
+    struct Widget {
+        value: u32,
+    }
+
+    impl Default for Widget {
+        fn default() -> Self {
+            Self { value: 0 }
+        }
+    }
+
+    impl Resettable for Widget {
+        fn reset(&mut self) {
+            self.value = 0;
+        }
+    }
+
+    #[test]
+    fn test_generic_pool_default_reset_policy() {
+        let pool: Arc<Pool<Widget, DefaultReset>> = Pool::new(4);
+
+        {
+            let mut w = pool.acquire();
+            w.value = 42;
+        } // returned and reset via Resettable::reset
+
+        let w = pool.acquire();
+        assert_eq!(w.value, 0);
+    }
 }